@@ -0,0 +1,245 @@
+//! The product formulas in various formats.
+//!
+//! Every format contains the geometric and the outer product. Coefficients are indexed like the
+//! elements of [generate_elements], see also the `basis` command.
+
+use algebraic_core::{
+    blade_name, generate_elements, generate_outer_product_sums,
+    generate_product_sums_with_signature,
+    shader::{generate_shader, ShaderLanguage, ShaderLayout},
+    Signature,
+};
+
+type Sums = Vec<Vec<(bool, usize, usize)>>;
+
+// formats the sum, `term` formats the product of the coefficients `a` and `b`
+fn format_sum(sum: &[(bool, usize, usize)], term: impl Fn(usize, usize) -> String) -> String {
+    sum.iter()
+        .enumerate()
+        .map(|(i, (n, a, b))| {
+            format!(
+                "{}{}",
+                match (i, n) {
+                    (0, false) => "",
+                    (0, true) => "-",
+                    (_, false) => " + ",
+                    (_, true) => " - ",
+                },
+                term(*a, *b)
+            )
+        })
+        .collect()
+}
+
+// (basis, geometric product sums, outer product sums)
+fn products(signature: &Signature) -> (Vec<String>, Sums, Sums) {
+    let elements = generate_elements(signature.dimension());
+    (
+        elements.iter().map(|element| blade_name(element)).collect(),
+        generate_product_sums_with_signature(&elements, signature),
+        generate_outer_product_sums(&elements),
+    )
+}
+
+fn description(signature: &Signature, basis: &[String]) -> String {
+    format!(
+        "Products of the geometric algebra with signature {:?}.\nCoefficients wrt. the basis [{}].",
+        signature.squares(),
+        basis.join(", ")
+    )
+}
+
+/// Functions with the same signature as the ones generated by `generate_geometric_product!`,
+/// the sums are the same as with [generate_product_string](algebraic_core::generate_product_string).
+pub fn rust(signature: &Signature) -> String {
+    let (basis, geometric, outer) = products(signature);
+    let length = basis.len();
+    let function = |name: &str, sums: &Sums| {
+        format!(
+            "pub fn {name}<A, B, T>(a: &A, b: &B) -> [T; {length}]
+where
+    A: ::core::ops::Index<usize, Output = T>,
+    B: ::core::ops::Index<usize, Output = T>,
+    T: Copy
+        + ::core::ops::Mul<Output = T>
+        + ::core::ops::Add<Output = T>
+        + ::core::ops::Sub<Output = T>,
+{{
+    [
+{}    ]
+}}
+",
+            sums.iter()
+                .map(|sum| format!(
+                    "        {},\n",
+                    format_sum(sum, |a, b| format!("a[{a}] * b[{b}]"))
+                ))
+                .collect::<String>()
+        )
+    };
+    format!(
+        "{}\n\n{}\n{}",
+        description(signature, &basis)
+            .lines()
+            .map(|line| format!("// {line}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        function("geometric_product", &geometric),
+        function("outer_product", &outer),
+    )
+}
+
+/// C99 functions over `double`, the output must not alias the inputs.
+pub fn c(signature: &Signature) -> String {
+    let (basis, geometric, outer) = products(signature);
+    let length = basis.len();
+    let function = |name: &str, sums: &Sums| {
+        let assignments = sums
+            .iter()
+            .enumerate()
+            .map(|(c, sum)| {
+                format!(
+                    "    out[{c}] = {};\n",
+                    format_sum(sum, |a, b| format!("a[{a}] * b[{b}]"))
+                )
+            })
+            .collect::<String>();
+        format!(
+            "static inline void {name}(const double a[restrict {length}], \
+             const double b[restrict {length}], double out[restrict {length}]) {{\n{assignments}}}\n"
+        )
+    };
+    format!(
+        "/*\n{}\n*/\n\n{}\n{}",
+        description(signature, &basis)
+            .lines()
+            .map(|line| format!(" * {line}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        function("geometric_product", &geometric),
+        function("outer_product", &outer),
+    )
+}
+
+/// See [generate_shader], the functions are emitted without prefix.
+pub fn shader(signature: &Signature, language: ShaderLanguage, layout: ShaderLayout) -> String {
+    let (basis, _, _) = products(signature);
+    format!(
+        "{}\n\n{}",
+        description(signature, &basis)
+            .lines()
+            .map(|line| format!("// {line}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        generate_shader(language, layout, signature, "")
+    )
+}
+
+/// `align*` environments, the coefficients are subscripted with their index.
+pub fn latex(signature: &Signature) -> String {
+    let (basis, geometric, outer) = products(signature);
+    let environment = |product: &str, sums: &Sums| {
+        let lines = sums
+            .iter()
+            .enumerate()
+            .map(|(c, sum)| {
+                format!(
+                    "  ({product})_{{{c}}} &= {}",
+                    format_sum(sum, |a, b| format!("a_{{{a}}} b_{{{b}}}"))
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" \\\\\n");
+        format!("\\begin{{align*}}\n{lines}\n\\end{{align*}}\n")
+    };
+    format!(
+        "{}\n\n{}\n{}",
+        description(signature, &basis)
+            .lines()
+            .map(|line| format!("% {line}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        environment("ab", &geometric),
+        environment("a \\wedge b", &outer),
+    )
+}
+
+/// An object with the signature, the basis names, and the product sums. Each coefficient's sum
+/// is a list of terms `[sign, a, b]`, e.g. `[-1, 2, 3]` for `- a[2] * b[3]`.
+pub fn json(signature: &Signature) -> String {
+    let (basis, geometric, outer) = products(signature);
+    let sums = |sums: &Sums| {
+        sums.iter()
+            .map(|sum| {
+                let terms = sum
+                    .iter()
+                    .map(|(n, a, b)| format!("[{}, {a}, {b}]", if *n { -1 } else { 1 }))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("    [{terms}]")
+            })
+            .collect::<Vec<_>>()
+            .join(",\n")
+    };
+    format!(
+        "{{\n  \"signature\": [{}],\n  \"basis\": [{}],\n  \"geometric_product\": [\n{}\n  ],\n  \"outer_product\": [\n{}\n  ]\n}}\n",
+        signature
+            .squares()
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+        basis
+            .iter()
+            .map(|name| format!("\"{name}\""))
+            .collect::<Vec<_>>()
+            .join(", "),
+        sums(&geometric),
+        sums(&outer),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_1d() {
+        assert_eq!(
+            json(&Signature::new(1, 0, 0)),
+            r#"{
+  "signature": [1],
+  "basis": ["1", "e1"],
+  "geometric_product": [
+    [[1, 0, 0], [1, 1, 1]],
+    [[1, 0, 1], [1, 1, 0]]
+  ],
+  "outer_product": [
+    [[1, 0, 0]],
+    [[1, 0, 1], [1, 1, 0]]
+  ]
+}
+"#
+        );
+    }
+
+    #[test]
+    fn latex_1d() {
+        assert_eq!(
+            latex(&Signature::negative(1)),
+            r"% Products of the geometric algebra with signature [-1].
+% Coefficients wrt. the basis [1, e1].
+
+\begin{align*}
+  (ab)_{0} &= a_{0} b_{0} - a_{1} b_{1} \\
+  (ab)_{1} &= a_{0} b_{1} + a_{1} b_{0}
+\end{align*}
+
+\begin{align*}
+  (a \wedge b)_{0} &= a_{0} b_{0} \\
+  (a \wedge b)_{1} &= a_{0} b_{1} + a_{1} b_{0}
+\end{align*}
+"
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Human readable tables about an algebra.
+
+use algebraic_core::{
+    blade_name, canonize_with_signature, element_type_name, generate_elements,
+    generate_outer_product_sums, generate_product_sums_with_signature, wedge_product, Signature,
+};
+
+// aligns the columns, the first row is the header
+fn table(rows: Vec<Vec<String>>) -> String {
+    let columns = rows.first().map_or(0, Vec::len);
+    let widths = (0..columns)
+        .map(|c| {
+            rows.iter()
+                .map(|row| row[c].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    rows.iter()
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:>width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            format!("{}\n", line.trim_end())
+        })
+        .collect()
+}
+
+/// Index, blade name, outer product of basis vectors, and type of each element.
+pub fn basis_table(signature: &Signature) -> String {
+    let dimension = signature.dimension();
+    let rows = [vec![
+        "Index".to_string(),
+        "Blade".to_string(),
+        "Outer Product".to_string(),
+        "Type".to_string(),
+    ]]
+    .into_iter()
+    .chain(
+        generate_elements(dimension)
+            .iter()
+            .enumerate()
+            .map(|(i, element)| {
+                vec![
+                    i.to_string(),
+                    blade_name(element),
+                    format!("{element:?}"),
+                    element_type_name(element.len(), dimension),
+                ]
+            }),
+    )
+    .collect();
+    format!("Signature {:?}\n\n{}", signature.squares(), table(rows))
+}
+
+/// Geometric product of each pair of elements, row times column.
+pub fn cayley_table(signature: &Signature) -> String {
+    let elements = generate_elements(signature.dimension());
+    let rows = [[String::new()]
+        .into_iter()
+        .chain(elements.iter().map(|b| blade_name(b)))
+        .collect()]
+    .into_iter()
+    .chain(elements.iter().map(|a| {
+        [blade_name(a)]
+            .into_iter()
+            .chain(elements.iter().map(|b| {
+                let mut c = wedge_product(a, b);
+                match canonize_with_signature(&mut c, signature) {
+                    None => "0".to_string(),
+                    Some(true) => format!("-{}", blade_name(&c)),
+                    Some(false) => blade_name(&c),
+                }
+            }))
+            .collect()
+    }))
+    .collect();
+    table(rows)
+}
+
+/// Number of terms in the sum of each coefficient of the geometric and outer products.
+pub fn term_counts(signature: &Signature) -> String {
+    let elements = generate_elements(signature.dimension());
+    let geometric = generate_product_sums_with_signature(&elements, signature);
+    let outer = generate_outer_product_sums(&elements);
+    let count = |sums: &[Vec<(bool, usize, usize)>]| sums.iter().map(Vec::len).sum::<usize>();
+
+    let rows = [vec![
+        "Blade".to_string(),
+        "Geometric".to_string(),
+        "Outer".to_string(),
+    ]]
+    .into_iter()
+    .chain(elements.iter().enumerate().map(|(i, element)| {
+        vec![
+            blade_name(element),
+            geometric[i].len().to_string(),
+            outer[i].len().to_string(),
+        ]
+    }))
+    .chain([vec![
+        "Total".to_string(),
+        count(&geometric).to_string(),
+        count(&outer).to_string(),
+    ]])
+    .collect();
+    table(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cayley_table_pga_2d() {
+        assert_eq!(
+            cayley_table(&Signature::from_squares(vec![0, 1, 1])),
+            "         1    e1     e2   e12    e3    e13   e23  e123
+   1     1    e1     e2   e12    e3    e13   e23  e123
+  e1    e1     0    e12     0   e13      0  e123     0
+  e2    e2  -e12      1   -e1   e23  -e123    e3  -e13
+ e12   e12     0     e1     0  e123      0   e13     0
+  e3    e3  -e13   -e23  e123     1    -e1   -e2   e12
+ e13   e13     0  -e123     0    e1      0  -e12     0
+ e23   e23  e123    -e3  -e13    e2    e12    -1   -e1
+e123  e123     0   -e13     0   e12      0   -e1     0
+"
+        );
+    }
+
+    #[test]
+    fn term_counts_3d() {
+        assert_eq!(
+            term_counts(&Signature::negative(3)),
+            "Blade  Geometric  Outer
+    1          8      1
+   e1          8      2
+   e2          8      2
+  e12          8      4
+   e3          8      2
+  e13          8      4
+  e23          8      4
+ e123          8      8
+Total         64     27
+"
+        );
+    }
+}
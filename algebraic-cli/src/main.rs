@@ -0,0 +1,146 @@
+//! Command-line tool for inspecting and exporting geometric algebras of any dimension and
+//! signature, built on [algebraic-core](https://docs.rs/algebraic-core/latest/algebraic_core).
+//!
+//! ```text
+//! algebraic-cli --dimension 3 basis
+//! algebraic-cli --signature 3,0,1 cayley
+//! algebraic-cli --squares 0,1,1,1 terms
+//! algebraic-cli --dimension 3 export wgsl --layout vec4
+//! ```
+mod export;
+mod inspect;
+
+use algebraic_core::{
+    shader::{ShaderLanguage, ShaderLayout},
+    Signature,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(flatten)]
+    algebra: Algebra,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Selects the algebra, i.e. its signature.
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct Algebra {
+    /// Dimension of the space, all basis vectors square to -1 (like in the algebraic crate)
+    #[arg(short, long)]
+    dimension: Option<usize>,
+
+    /// Signature Cl(p, q, r) given as `p,q,r`: p basis vectors square to 1, then q to -1, and the
+    /// last r to 0
+    #[arg(short, long, value_parser = parse_pqr)]
+    signature: Option<Signature>,
+
+    /// Explicit squares of the basis vectors, e.g. `0,1,1,1`
+    #[arg(long, value_parser = parse_squares, allow_hyphen_values = true)]
+    squares: Option<Signature>,
+}
+
+impl Algebra {
+    fn signature(self) -> Signature {
+        match (self.dimension, self.signature, self.squares) {
+            (Some(dimension), _, _) => Signature::negative(dimension),
+            (_, Some(signature), _) | (_, _, Some(signature)) => signature,
+            _ => unreachable!("clap requires one of the arguments"),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the basis table
+    Basis,
+    /// Print the Cayley table of the geometric product
+    Cayley,
+    /// Print the number of terms of the products per coefficient
+    Terms,
+    /// Export the formulas of the geometric and outer products
+    Export {
+        format: Format,
+
+        /// Layout of the coefficients, only used by the shader formats
+        #[arg(long, value_enum, default_value_t = Layout::Array)]
+        layout: Layout,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Rust,
+    C,
+    Wgsl,
+    Glsl,
+    Latex,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Layout {
+    Array,
+    Vec4,
+}
+
+impl From<Layout> for ShaderLayout {
+    fn from(layout: Layout) -> Self {
+        match layout {
+            Layout::Array => ShaderLayout::Array,
+            Layout::Vec4 => ShaderLayout::Vec4,
+        }
+    }
+}
+
+fn parse_list<T: std::str::FromStr>(list: &str) -> Result<Vec<T>, String> {
+    list.split(',')
+        .map(|item| {
+            item.trim()
+                .parse()
+                .map_err(|_| format!("invalid entry `{item}`"))
+        })
+        .collect()
+}
+
+fn parse_pqr(pqr: &str) -> Result<Signature, String> {
+    match parse_list(pqr)?.as_slice() {
+        &[p, q, r] => Ok(Signature::new(p, q, r)),
+        _ => Err("expected three numbers `p,q,r`".to_string()),
+    }
+}
+
+fn parse_squares(squares: &str) -> Result<Signature, String> {
+    let squares = parse_list::<i8>(squares)?;
+    if let Some(square) = squares.iter().find(|s| !(-1..=1).contains(*s)) {
+        return Err(format!(
+            "basis vectors have to square to 1, -1, or 0, got {square}"
+        ));
+    }
+    Ok(Signature::from_squares(squares))
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let signature = cli.algebra.signature();
+
+    let output = match cli.command {
+        Command::Basis => inspect::basis_table(&signature),
+        Command::Cayley => inspect::cayley_table(&signature),
+        Command::Terms => inspect::term_counts(&signature),
+        Command::Export { format, layout } => match format {
+            Format::Rust => export::rust(&signature),
+            Format::C => export::c(&signature),
+            Format::Wgsl => export::shader(&signature, ShaderLanguage::Wgsl, layout.into()),
+            Format::Glsl => export::shader(&signature, ShaderLanguage::Glsl, layout.into()),
+            Format::Latex => export::latex(&signature),
+            Format::Json => export::json(&signature),
+        },
+    };
+
+    print!("{output}");
+}
@@ -0,0 +1,251 @@
+//! The core of the generation: elements (base) and product sums.
+//!
+//! The functions are not optimal performance wise, the focus is on clarity.
+use crate::Signature;
+
+/// Canonizes a given element and returns the sign.
+///
+/// An element is given as the list of basis vector indices whose product it is. Canonization
+/// sorts these indices and eliminates duplicates, `true` is returned if this negates the element.
+/// Note that basis vectors square to `-1`, so eliminating a pair of duplicates flips the sign.
+pub fn canonize(element: &mut Vec<usize>) -> bool {
+    canonize_with(element, |_| -1).unwrap()
+}
+
+/// Canonizes a given element wrt. a signature and returns the sign.
+///
+/// Like [canonize], but eliminating a pair of duplicates only flips the sign if the basis vector
+/// squares to `-1`. If it squares to `0`, the whole element vanishes and `None` is returned.
+pub fn canonize_with_signature(element: &mut Vec<usize>, signature: &Signature) -> Option<bool> {
+    canonize_with(element, |d| signature.squares()[d])
+}
+
+fn canonize_with(element: &mut Vec<usize>, square: impl Fn(usize) -> i8) -> Option<bool> {
+    // swapping and eliminating duplicates (depending on square) flips sign
+    let mut negate = false;
+
+    // sort with bubblesort, keeping track of sign
+    for _ in 0..element.len() {
+        for i in 1..element.len() {
+            if element[i - 1] > element[i] {
+                element.swap(i - 1, i);
+                negate ^= true;
+            }
+        }
+    }
+
+    // remove pairs of duplicates, again tracking sign
+    for _ in 0..element.len() {
+        for i in 1..element.len() {
+            if element[i - 1] == element[i] {
+                match square(element[i]) {
+                    0 => return None,
+                    -1 => negate ^= true,
+                    _ => {}
+                }
+                element.remove(i);
+                element.remove(i - 1);
+                break;
+            }
+        }
+    }
+
+    Some(negate)
+}
+
+/// Concatenation of the basis vector indices, the result is not canonized.
+pub fn wedge_product(a: &[usize], b: &[usize]) -> Vec<usize> {
+    a.iter().chain(b.iter()).cloned().collect()
+}
+
+/// Generates all the elements of a geometric algebra of a given dimension.
+///
+/// Elements are ordered such that the `i`-th element contains the basis vectors corresponding to
+/// the set bits of `i`.
+pub fn generate_elements(dimension: usize) -> Vec<Vec<usize>> {
+    // starting with scalar
+    let mut elements: Vec<Vec<usize>> = vec![vec![]];
+
+    for d in 0..dimension {
+        // these are the basis vectors
+        elements.push(vec![d]);
+
+        // now generate all the bi- tri- etc. vectors up to pseudoscalar
+        loop {
+            let mut new = Vec::new();
+
+            // slow. but offline
+            for a in &elements {
+                for b in &elements {
+                    let mut c = wedge_product(a, b);
+                    canonize(&mut c); // we don't care about sign here
+
+                    // careful not to add duplicates
+                    if !elements.contains(&c) && !new.contains(&c) {
+                        new.push(c);
+                    }
+                }
+            }
+            if new.is_empty() {
+                break;
+            }
+            elements.extend(new);
+        }
+    }
+
+    elements
+}
+
+/// Generates the geometric product.
+///
+/// Basically, multiply out and see to which element is contributed (and under which sign).
+/// Entry `c` of the result lists the terms `(negate, a, b)` of the sum for coefficient `c`.
+pub fn generate_product_sums(elements: &[Vec<usize>]) -> Vec<Vec<(bool, usize, usize)>> {
+    let dimension = elements.len().trailing_zeros() as usize;
+    generate_product_sums_with_signature(elements, &Signature::negative(dimension))
+}
+
+/// Generates the geometric product wrt. a signature.
+///
+/// Like [generate_product_sums], but terms that vanish because of a degenerate signature are
+/// left out.
+pub fn generate_product_sums_with_signature(
+    elements: &[Vec<usize>],
+    signature: &Signature,
+) -> Vec<Vec<(bool, usize, usize)>> {
+    let mut sums: Vec<Vec<(bool, usize, usize)>> = vec![vec![]; elements.len()];
+    for (a_i, a) in elements.iter().enumerate() {
+        for (b_i, b) in elements.iter().enumerate() {
+            let mut c = wedge_product(a, b);
+            let Some(negate) = canonize_with_signature(&mut c, signature) else {
+                continue;
+            };
+            let c_i = elements.iter().position(|s| *s == c).unwrap();
+            sums[c_i].push((negate, a_i, b_i));
+        }
+    }
+    sums
+}
+
+/// Generates the outer (wedge) product.
+///
+/// Same format as [generate_product_sums], but only the terms of elements without common basis
+/// vectors contribute. This doesn't depend on the signature.
+pub fn generate_outer_product_sums(elements: &[Vec<usize>]) -> Vec<Vec<(bool, usize, usize)>> {
+    let mut sums: Vec<Vec<(bool, usize, usize)>> = vec![vec![]; elements.len()];
+    for (a_i, a) in elements.iter().enumerate() {
+        for (b_i, b) in elements.iter().enumerate() {
+            if a.iter().any(|d| b.contains(d)) {
+                continue;
+            }
+            let mut c = wedge_product(a, b);
+            let negate = canonize(&mut c);
+            let c_i = elements.iter().position(|s| *s == c).unwrap();
+            sums[c_i].push((negate, a_i, b_i));
+        }
+    }
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonization() {
+        let mut e = vec![];
+        assert!(!canonize(&mut e));
+        assert_eq!(e, vec![]);
+
+        let mut e = vec![0, 0];
+        assert!(canonize(&mut e));
+        assert_eq!(e, vec![]);
+
+        let mut e = vec![1, 0];
+        assert!(canonize(&mut e));
+        assert_eq!(e, vec![0, 1]);
+
+        let mut e = vec![2, 1, 0];
+        assert!(canonize(&mut e));
+        assert_eq!(e, vec![0, 1, 2]);
+
+        let mut e = vec![0, 2, 0];
+        assert!(!canonize(&mut e));
+        assert_eq!(e, vec![2]);
+    }
+
+    #[test]
+    fn element_generation() {
+        assert_eq!(
+            generate_elements(3),
+            vec![
+                vec![],
+                vec![0],
+                vec![1],
+                vec![0, 1],
+                vec![2],
+                vec![0, 2],
+                vec![1, 2],
+                vec![0, 1, 2],
+            ]
+        )
+    }
+
+    #[test]
+    fn product_sum_generation() {
+        let elements = generate_elements(2);
+        assert_eq!(
+            generate_product_sums(&elements),
+            vec![
+                vec![(false, 0, 0), (true, 1, 1), (true, 2, 2), (true, 3, 3)],
+                vec![(false, 0, 1), (false, 1, 0), (false, 2, 3), (true, 3, 2)],
+                vec![(false, 0, 2), (true, 1, 3), (false, 2, 0), (false, 3, 1)],
+                vec![(false, 0, 3), (false, 1, 2), (true, 2, 1), (false, 3, 0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn canonization_with_signature() {
+        let signature = Signature::from_squares(vec![1, -1, 0]);
+
+        let mut e = vec![0, 0];
+        assert_eq!(canonize_with_signature(&mut e, &signature), Some(false));
+        assert_eq!(e, vec![]);
+
+        let mut e = vec![1, 0, 1];
+        assert_eq!(canonize_with_signature(&mut e, &signature), Some(false));
+        assert_eq!(e, vec![0]);
+
+        let mut e = vec![2, 1, 2];
+        assert_eq!(canonize_with_signature(&mut e, &signature), None);
+    }
+
+    #[test]
+    fn product_sum_generation_with_signature() {
+        let elements = generate_elements(2);
+        assert_eq!(
+            generate_product_sums_with_signature(&elements, &Signature::new(1, 0, 1)),
+            vec![
+                vec![(false, 0, 0), (false, 1, 1)],
+                vec![(false, 0, 1), (false, 1, 0)],
+                vec![(false, 0, 2), (false, 1, 3), (false, 2, 0), (true, 3, 1)],
+                vec![(false, 0, 3), (false, 1, 2), (true, 2, 1), (false, 3, 0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn outer_product_sum_generation() {
+        let elements = generate_elements(2);
+        assert_eq!(
+            generate_outer_product_sums(&elements),
+            vec![
+                vec![(false, 0, 0)],
+                vec![(false, 0, 1), (false, 1, 0)],
+                vec![(false, 0, 2), (false, 2, 0)],
+                vec![(false, 0, 3), (false, 1, 2), (true, 2, 1), (false, 3, 0)],
+            ]
+        );
+    }
+}
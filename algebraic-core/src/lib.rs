@@ -0,0 +1,137 @@
+//! Generation logic for geometric products of any dimension.
+//!
+//! This is the logic behind
+//! [algebraic-gen](https://docs.rs/algebraic-gen/latest/algebraic_gen). Proc-macro crates cannot
+//! export anything but proc-macros, so the generation lives here, in a normal library crate.
+//! That way it is reachable from build scripts and other tools, e.g. to emit code for other
+//! targets.
+//!
+//! # Example
+//!
+//! Generating the same product that `generate_geometric_product!(my_product, 2)` would generate,
+//! but as a string, e.g. to write it to a file in a build script:
+//!
+//! ```rust
+//! use algebraic_core::{generate_elements, generate_product_sums, generate_product_string};
+//!
+//! let elements = generate_elements(2);
+//! let product_sums = generate_product_sums(&elements);
+//!
+//! assert_eq!(
+//!     generate_product_string(&product_sums),
+//!     "[a[0] * b[0] - a[1] * b[1] - a[2] * b[2] - a[3] * b[3],\
+//!     a[0] * b[1] + a[1] * b[0] + a[2] * b[3] - a[3] * b[2],\
+//!     a[0] * b[2] - a[1] * b[3] + a[2] * b[0] + a[3] * b[1],\
+//!     a[0] * b[3] + a[1] * b[2] - a[2] * b[1] + a[3] * b[0]]"
+//! );
+//! ```
+//!
+//! # On the Generation
+//!
+//! The generation logic is inspired by
+//! [All Hail Geometric Algebra!](https://crypto.stanford.edu/~blynn/haskell/ga.html).
+//!
+//! * Generates elements (base), see [generate_elements]
+//!   * Product is simple concatenation here, see [wedge_product]
+//!   * Canonization with Bubblesort & a kind of duplication elimination, see [canonize]
+//! * Generates product sums, see [generate_product_sums]
+//!   * Expand terms
+//!   * More products & canonization
+//!   * Adding/Subtracting based on sign returned by canonization
+//! * A bit of formatting, see [generate_product_string] and [generate_base_string]
+//!
+//! Apart from the crate's default, where all basis vectors square to `-1`, other metric
+//! signatures are supported via [Signature], e.g. [generate_product_sums_with_signature].
+//! Also, the [shader] module emits the products as WGSL or GLSL functions.
+
+mod algebra_generation;
+pub mod shader;
+mod signature;
+pub use algebra_generation::{
+    canonize, canonize_with_signature, generate_elements, generate_outer_product_sums,
+    generate_product_sums, generate_product_sums_with_signature, wedge_product,
+};
+pub use signature::Signature;
+
+/// Formats the product sums as a Rust array expression over the arguments `a` and `b`.
+pub fn generate_product_string(product_sums: &[Vec<(bool, usize, usize)>]) -> String {
+    format!(
+        "[{}]",
+        product_sums
+            .iter()
+            .map(|sum| {
+                sum.iter()
+                    .enumerate()
+                    .map(|(i, (n, a, b))| {
+                        format!(
+                            "{}a[{a}] * b[{b}]",
+                            if i == 0 {
+                                ""
+                            } else if *n {
+                                "- "
+                            } else {
+                                "+ "
+                            },
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+/// Formats the elements as a markdown table, used for documenting the generated products.
+pub fn generate_base_string(elements: &[Vec<usize>]) -> String {
+    let dimension = elements.len().trailing_zeros() as usize;
+    elements.iter().enumerate().fold(
+        "\n\n|Index|Blade|Outer Product|Type|\n|-|-|-|-|".to_string(),
+        |bases, (i, element)| {
+            format!(
+                "{bases}\n|{}|{}|{:?}|{}|",
+                i,
+                blade_name(element),
+                element,
+                element_type_name(element.len(), dimension)
+            )
+        },
+    )
+}
+
+/// Names the type of element by its grade, e.g. "Bivector" for grade `2`.
+pub fn element_type_name(grade: usize, dimension: usize) -> String {
+    match grade {
+        0 => "Scalar".to_string(),
+        k if k == dimension => "Pseudoscalar".to_string(),
+        1 => "Vector".to_string(),
+        2 => "Bivector".to_string(),
+        3 => "Trivector".to_string(),
+        4 => "Quadvector".to_string(),
+        5 => "Quintvector".to_string(),
+        k => format!("{}-Vector", k),
+    }
+}
+
+/// Names the element as a blade, counting basis vectors from one, e.g. `e13` for `[0, 2]`.
+///
+/// The scalar element `[]` is named `1`. Above nine dimensions, the indices are separated by
+/// underscores, e.g. `e1_10`.
+pub fn blade_name(element: &[usize]) -> String {
+    if element.is_empty() {
+        return "1".to_string();
+    }
+    let separator = if element.iter().all(|&d| d < 9) {
+        ""
+    } else {
+        "_"
+    };
+    format!(
+        "e{}",
+        element
+            .iter()
+            .map(|d| (d + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(separator)
+    )
+}
@@ -0,0 +1,389 @@
+//! Emits the products as WGSL or GLSL functions, e.g. to run the same products on the GPU.
+//!
+//! The emitted functions use the same sums as [generate_product_string](crate::generate_product_string)
+//! and operate on `f32` coefficients, see [generate_shader].
+
+use crate::{
+    generate_elements, generate_outer_product_sums, generate_product_sums_with_signature, Signature,
+};
+
+/// The shading language of the emitted functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShaderLanguage {
+    Wgsl,
+    Glsl,
+}
+
+/// How the `2^D` coefficients of a multivector are laid out in the emitted functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShaderLayout {
+    /// One array of scalars, `array<f32, N>` in WGSL and `float[N]` in GLSL.
+    Array,
+    /// Packed into four-component vectors: a single `vec4` for up to `4` coefficients (padded
+    /// with zeros), an array of `N / 4` of them otherwise.
+    Vec4,
+}
+
+impl ShaderLayout {
+    fn access(&self, argument: &str, index: usize, base_size: usize) -> String {
+        match self {
+            ShaderLayout::Array => format!("{argument}[{index}]"),
+            ShaderLayout::Vec4 if base_size <= 4 => format!("{argument}.{}", component(index)),
+            ShaderLayout::Vec4 => format!("{argument}[{}].{}", index / 4, component(index)),
+        }
+    }
+}
+
+fn component(index: usize) -> char {
+    ['x', 'y', 'z', 'w'][index % 4]
+}
+
+/// Generates the shader functions for the geometric algebra with the given signature.
+///
+/// The following functions are emitted, each name starting with `prefix` so that several
+/// algebras can live in the same shader:
+/// * `reverse(a)`: Reverses the order of the basis vectors in each element
+/// * `geometric_product(a, b)`
+/// * `outer_product(a, b)`
+/// * `sandwich_product(a, b)`: `a * b * reverse(a)`
+///
+/// The coefficients are ordered like the elements of [generate_elements].
+pub fn generate_shader(
+    language: ShaderLanguage,
+    layout: ShaderLayout,
+    signature: &Signature,
+    prefix: &str,
+) -> String {
+    let elements = generate_elements(signature.dimension());
+    let base_size = elements.len();
+
+    let reverse = elements
+        .iter()
+        .enumerate()
+        .map(|(i, element)| {
+            // reversing k vectors takes k(k-1)/2 swaps
+            let negate = element.len() % 4 >= 2;
+            format!(
+                "{}{}",
+                if negate { "-" } else { "" },
+                layout.access("a", i, base_size)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let format_sums = |sums: Vec<Vec<(bool, usize, usize)>>| {
+        sums.iter()
+            .map(|sum| {
+                let terms = sum
+                    .iter()
+                    .map(|(n, a, b)| {
+                        let product = format!(
+                            "{} * {}",
+                            layout.access("a", *a, base_size),
+                            layout.access("b", *b, base_size),
+                        );
+                        (*n, product)
+                    })
+                    .collect::<Vec<_>>();
+                format_sum(&terms)
+            })
+            .collect::<Vec<_>>()
+    };
+    let geometric_product = format_sums(generate_product_sums_with_signature(&elements, signature));
+    let outer_product = format_sums(generate_outer_product_sums(&elements));
+
+    let emitter = Emitter {
+        language,
+        layout,
+        base_size,
+        prefix,
+    };
+    [
+        emitter.function("reverse", &["a"], &emitter.construct(&reverse)),
+        emitter.function(
+            "geometric_product",
+            &["a", "b"],
+            &emitter.construct(&geometric_product),
+        ),
+        emitter.function(
+            "outer_product",
+            &["a", "b"],
+            &emitter.construct(&outer_product),
+        ),
+        emitter.function(
+            "sandwich_product",
+            &["a", "b"],
+            &format!(
+                "{prefix}geometric_product({prefix}geometric_product(a, b), {prefix}reverse(a))"
+            ),
+        ),
+    ]
+    .join("\n")
+}
+
+// the most terms in one (parenthesized) sum
+const MAX_TERMS: usize = 8;
+
+// Joins the (negated, term) pairs into a sum. Long sums are nested into parenthesized groups of
+// at most `MAX_TERMS`, so that the expression depth, which the shader compilers parse recursively,
+// only grows logarithmically with the dimension.
+fn format_sum(terms: &[(bool, String)]) -> String {
+    if terms.len() <= MAX_TERMS {
+        return terms
+            .iter()
+            .enumerate()
+            .map(|(i, (n, term))| {
+                let sign = match (i, n) {
+                    (0, false) => "",
+                    (0, true) => "-",
+                    (_, false) => " + ",
+                    (_, true) => " - ",
+                };
+                format!("{sign}{term}")
+            })
+            .collect();
+    }
+    let groups = terms
+        .chunks(terms.len().div_ceil(MAX_TERMS))
+        .map(|group| (false, format!("({})", format_sum(group))))
+        .collect::<Vec<_>>();
+    format_sum(&groups)
+}
+
+struct Emitter<'a> {
+    language: ShaderLanguage,
+    layout: ShaderLayout,
+    base_size: usize,
+    prefix: &'a str,
+}
+
+impl Emitter<'_> {
+    fn vec4(&self) -> &'static str {
+        match self.language {
+            ShaderLanguage::Wgsl => "vec4<f32>",
+            ShaderLanguage::Glsl => "vec4",
+        }
+    }
+
+    fn scalar(&self) -> &'static str {
+        match self.language {
+            ShaderLanguage::Wgsl => "f32",
+            ShaderLanguage::Glsl => "float",
+        }
+    }
+
+    // (element type, array length) of the multivector type
+    fn element_and_length(&self) -> (&'static str, Option<usize>) {
+        match self.layout {
+            ShaderLayout::Array => (self.scalar(), Some(self.base_size)),
+            ShaderLayout::Vec4 if self.base_size <= 4 => (self.vec4(), None),
+            ShaderLayout::Vec4 => (self.vec4(), Some(self.base_size / 4)),
+        }
+    }
+
+    fn type_name(&self) -> String {
+        match (self.language, self.element_and_length()) {
+            (_, (element, None)) => element.to_string(),
+            (ShaderLanguage::Wgsl, (element, Some(length))) => {
+                format!("array<{element}, {length}>")
+            }
+            (ShaderLanguage::Glsl, (element, Some(length))) => format!("{element}[{length}]"),
+        }
+    }
+
+    fn parameter(&self, name: &str) -> String {
+        match (self.language, self.element_and_length()) {
+            (ShaderLanguage::Wgsl, _) => format!("{name}: {}", self.type_name()),
+            (ShaderLanguage::Glsl, (element, None)) => format!("{element} {name}"),
+            (ShaderLanguage::Glsl, (element, Some(length))) => {
+                format!("{element} {name}[{length}]")
+            }
+        }
+    }
+
+    // constructs the multivector from the expressions for its coefficients
+    fn construct(&self, coefficients: &[String]) -> String {
+        let lines =
+            |arguments: &[String]| format!("\n        {}\n    ", arguments.join(",\n        "));
+        match self.layout {
+            ShaderLayout::Array => format!("{}({})", self.type_name(), lines(coefficients)),
+            ShaderLayout::Vec4 => {
+                let zero = ["0.0".to_string()];
+                let mut vectors = coefficients.chunks(4).map(|chunk| {
+                    chunk
+                        .iter()
+                        .chain(zero.iter().cycle())
+                        .take(4)
+                        .cloned()
+                        .collect::<Vec<_>>()
+                });
+                if self.base_size <= 4 {
+                    format!("{}({})", self.vec4(), lines(&vectors.next().unwrap()))
+                } else {
+                    let vectors = vectors
+                        .map(|vector| format!("{}({})", self.vec4(), vector.join(", ")))
+                        .collect::<Vec<_>>();
+                    format!("{}({})", self.type_name(), lines(&vectors))
+                }
+            }
+        }
+    }
+
+    fn function(&self, name: &str, parameters: &[&str], body: &str) -> String {
+        let prefix = self.prefix;
+        let parameters = parameters
+            .iter()
+            .map(|parameter| self.parameter(parameter))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let type_name = self.type_name();
+        match self.language {
+            ShaderLanguage::Wgsl => format!(
+                "fn {prefix}{name}({parameters}) -> {type_name} {{\n    return {body};\n}}\n"
+            ),
+            ShaderLanguage::Glsl => {
+                format!("{type_name} {prefix}{name}({parameters}) {{\n    return {body};\n}}\n")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signatures() -> Vec<Signature> {
+        vec![
+            Signature::negative(0),
+            Signature::negative(1),
+            Signature::negative(2),
+            Signature::negative(3),
+            Signature::new(3, 0, 1),
+            Signature::new(4, 1, 0),
+            // sums of 64 terms, which overflowed the stack of the shader compilers without grouping
+            Signature::negative(6),
+        ]
+    }
+
+    #[test]
+    fn wgsl_validates() {
+        for signature in signatures() {
+            for layout in [ShaderLayout::Array, ShaderLayout::Vec4] {
+                let source = generate_shader(ShaderLanguage::Wgsl, layout, &signature, "ga_");
+                let module = naga::front::wgsl::parse_str(&source)
+                    .unwrap_or_else(|err| panic!("{}\n{source}", err.emit_to_string(&source)));
+                naga::valid::Validator::new(
+                    naga::valid::ValidationFlags::all(),
+                    naga::valid::Capabilities::all(),
+                )
+                .validate(&module)
+                .unwrap_or_else(|err| panic!("{err:?}\n{source}"));
+            }
+        }
+    }
+
+    #[test]
+    fn glsl_validates() {
+        for signature in signatures() {
+            for layout in [ShaderLayout::Array, ShaderLayout::Vec4] {
+                // the GLSL frontend wants a complete shader
+                let source = format!(
+                    "#version 450\nlayout(local_size_x = 1) in;\n{}\nvoid main() {{}}\n",
+                    generate_shader(ShaderLanguage::Glsl, layout, &signature, "ga_")
+                );
+                let module = naga::front::glsl::Frontend::default()
+                    .parse(
+                        &naga::front::glsl::Options::from(naga::ShaderStage::Compute),
+                        &source,
+                    )
+                    .unwrap_or_else(|err| panic!("{}\n{source}", err.emit_to_string(&source)));
+                naga::valid::Validator::new(
+                    naga::valid::ValidationFlags::all(),
+                    naga::valid::Capabilities::all(),
+                )
+                .validate(&module)
+                .unwrap_or_else(|err| panic!("{err:?}\n{source}"));
+            }
+        }
+    }
+
+    #[test]
+    fn wgsl_2d() {
+        assert_eq!(
+            generate_shader(
+                ShaderLanguage::Wgsl,
+                ShaderLayout::Vec4,
+                &Signature::negative(2),
+                ""
+            ),
+            "fn reverse(a: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(
+        a.x,
+        a.y,
+        a.z,
+        -a.w
+    );
+}
+
+fn geometric_product(a: vec4<f32>, b: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(
+        a.x * b.x - a.y * b.y - a.z * b.z - a.w * b.w,
+        a.x * b.y + a.y * b.x + a.z * b.w - a.w * b.z,
+        a.x * b.z - a.y * b.w + a.z * b.x + a.w * b.y,
+        a.x * b.w + a.y * b.z - a.z * b.y + a.w * b.x
+    );
+}
+
+fn outer_product(a: vec4<f32>, b: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(
+        a.x * b.x,
+        a.x * b.y + a.y * b.x,
+        a.x * b.z + a.z * b.x,
+        a.x * b.w + a.y * b.z - a.z * b.y + a.w * b.x
+    );
+}
+
+fn sandwich_product(a: vec4<f32>, b: vec4<f32>) -> vec4<f32> {
+    return geometric_product(geometric_product(a, b), reverse(a));
+}
+"
+        );
+    }
+
+    #[test]
+    fn glsl_1d() {
+        assert_eq!(
+            generate_shader(
+                ShaderLanguage::Glsl,
+                ShaderLayout::Array,
+                &Signature::negative(1),
+                "ga1_"
+            ),
+            "float[2] ga1_reverse(float a[2]) {
+    return float[2](
+        a[0],
+        a[1]
+    );
+}
+
+float[2] ga1_geometric_product(float a[2], float b[2]) {
+    return float[2](
+        a[0] * b[0] - a[1] * b[1],
+        a[0] * b[1] + a[1] * b[0]
+    );
+}
+
+float[2] ga1_outer_product(float a[2], float b[2]) {
+    return float[2](
+        a[0] * b[0],
+        a[0] * b[1] + a[1] * b[0]
+    );
+}
+
+float[2] ga1_sandwich_product(float a[2], float b[2]) {
+    return ga1_geometric_product(ga1_geometric_product(a, b), ga1_reverse(a));
+}
+"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! The metric signature of a geometric algebra.
+
+/// What the basis vectors of a geometric algebra square to, each of `1`, `-1`, or `0`.
+///
+/// The products generated by `generate_geometric_product!` use [Signature::negative], i.e. all
+/// basis vectors square to `-1`. The conventional `Cl(p, q, r)` is given by [Signature::new].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Signature {
+    squares: Vec<i8>,
+}
+
+impl Signature {
+    /// `Cl(p, q, r)`: The first `p` basis vectors square to `1`, the next `q` to `-1`, and the
+    /// last `r` to `0`.
+    pub fn new(p: usize, q: usize, r: usize) -> Self {
+        Self {
+            squares: [vec![1; p], vec![-1; q], vec![0; r]].concat(),
+        }
+    }
+
+    /// All `dimension` basis vectors square to `-1`, i.e. `Cl(0, dimension, 0)`.
+    pub fn negative(dimension: usize) -> Self {
+        Self::new(0, dimension, 0)
+    }
+
+    /// The `i`-th basis vector squares to `squares[i]`.
+    ///
+    /// # Panics
+    ///
+    /// If any of the squares is not one of `1`, `-1`, or `0`.
+    pub fn from_squares(squares: Vec<i8>) -> Self {
+        assert!(
+            squares.iter().all(|s| (-1..=1).contains(s)),
+            "basis vectors have to square to 1, -1, or 0, got {squares:?}"
+        );
+        Self { squares }
+    }
+
+    /// The dimension of the space, i.e. the number of basis vectors.
+    pub fn dimension(&self) -> usize {
+        self.squares.len()
+    }
+
+    /// What the basis vectors square to.
+    pub fn squares(&self) -> &[i8] {
+        &self.squares
+    }
+}
@@ -58,6 +58,26 @@
 //! }
 //! ```
 //!
+//! # Signatures
+//!
+//! By default, all basis vectors square to `-1`. Instead of the dimension, the squares of the
+//! basis vectors can be given as an array of `1`, `-1`, and `0`, e.g. for the projective geometric
+//! algebra `Cl(3,0,1)`, where the last basis vector squares to `0`:
+//!
+//! ```rust
+//! use algebraic_gen::generate_geometric_product;
+//!
+//! generate_geometric_product!(pga_product, [1, 1, 1, 0]);
+//!
+//! fn main() {
+//!   let e1 = [0., 1., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0.];
+//!   let e4 = [0., 0., 0., 0., 0., 0., 0., 0., 1., 0., 0., 0., 0., 0., 0., 0.];
+//!
+//!   assert_eq!(pga_product(&e1, &e1)[0], 1.0);
+//!   assert_eq!(pga_product(&e4, &e4), [0.0; 16]);
+//! }
+//! ```
+//!
 //! # Geometric Algebra
 //!
 //! A [geometric algebra](https://en.wikipedia.org/wiki/Geometric_algebra) is defined with respect
@@ -105,72 +125,29 @@
 //!   * Adding/Subtracting based on sign returned by canonization
 //! * A bit of formatting
 //!
-//! More details in the source code:
-//! [`algebra_generation.rs`](../src/algebraic_gen/algebra_generation.rs.html).
+//! The generation logic itself lives in
+//! [algebraic-core](https://docs.rs/algebraic-core/latest/algebraic_core), a normal library crate,
+//! so that it can also be used from build scripts and other tools.
 
-mod algebra_generation;
-use algebra_generation::{generate_elements, generate_product_sums};
+use algebraic_core::{
+    generate_base_string, generate_elements, generate_product_string,
+    generate_product_sums_with_signature, Signature,
+};
 use core::str::FromStr;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse::Parse, parse_macro_input, Error, ExprArray};
-
-fn generate_product_string(product_sums: &[Vec<(bool, usize, usize)>]) -> String {
-    format!(
-        "[{}]",
-        product_sums
-            .iter()
-            .map(|sum| {
-                sum.iter()
-                    .enumerate()
-                    .map(|(i, (n, a, b))| {
-                        format!(
-                            "{}a[{a}] * b[{b}]",
-                            if i == 0 {
-                                ""
-                            } else if *n {
-                                "- "
-                            } else {
-                                "+ "
-                            },
-                        )
-                    })
-                    .collect::<Vec<_>>()
-                    .join(" ")
-            })
-            .collect::<Vec<_>>()
-            .join(","),
-    )
-}
+use syn::{parse::Parse, parse_macro_input, Error, Expr, ExprArray, ExprLit, ExprUnary, Lit, UnOp};
 
-fn generate_base_string(elements: &[Vec<usize>]) -> String {
-    let dimension = elements.len().trailing_zeros() as usize;
-    elements.iter().enumerate().fold(
-        "\n\n|Index|Outer Product|Type|\n|-|-|-|".to_string(),
-        |bases, (i, element)| {
-            format!(
-                "{bases}\n|{}|{:?}|{}|",
-                i,
-                element,
-                match element.len() {
-                    0 => "Scalar".to_string(),
-                    k if k == dimension => "Pseudoscalar".to_string(),
-                    1 => "Vector".to_string(),
-                    2 => "Bivector".to_string(),
-                    3 => "Trivector".to_string(),
-                    4 => "Quadvector".to_string(),
-                    5 => "Quintvector".to_string(),
-                    k => format!("{}-Vector", k),
-                }
-            )
-        },
-    )
+// either the dimension, all basis vectors squaring to `-1`, or the squares of the basis vectors
+enum Space {
+    Dimension(syn::LitInt),
+    Squares(ExprArray),
 }
 
 struct MacroArgs {
     function_ident: syn::Ident,
     _comma: syn::token::Comma,
-    dimension: syn::LitInt,
+    space: Space,
 }
 
 impl Parse for MacroArgs {
@@ -178,29 +155,70 @@ impl Parse for MacroArgs {
         Ok(MacroArgs {
             function_ident: input.parse()?,
             _comma: input.parse()?,
-            dimension: input.parse()?,
+            space: if input.peek(syn::token::Bracket) {
+                Space::Squares(input.parse()?)
+            } else {
+                Space::Dimension(input.parse()?)
+            },
         })
     }
 }
 
+impl Space {
+    fn signature(&self) -> syn::Result<Signature> {
+        match self {
+            Space::Dimension(dimension) => {
+                Ok(Signature::negative(dimension.base10_parse::<usize>()?))
+            }
+            Space::Squares(squares) => squares
+                .elems
+                .iter()
+                .map(|square| {
+                    let value = match square {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Int(int), ..
+                        }) => int.base10_parse::<i8>()?,
+                        Expr::Unary(ExprUnary {
+                            op: UnOp::Neg(_),
+                            expr,
+                            ..
+                        }) => match expr.as_ref() {
+                            Expr::Lit(ExprLit {
+                                lit: Lit::Int(int), ..
+                            }) => -int.base10_parse::<i8>()?,
+                            _ => return Err(Error::new_spanned(square, "expected an integer")),
+                        },
+                        _ => return Err(Error::new_spanned(square, "expected an integer")),
+                    };
+                    match value {
+                        -1..=1 => Ok(value),
+                        _ => Err(Error::new_spanned(
+                            square,
+                            "basis vectors have to square to 1, -1, or 0",
+                        )),
+                    }
+                })
+                .collect::<syn::Result<Vec<_>>>()
+                .map(Signature::from_squares),
+        }
+    }
+}
+
 /// The one macro exported by this crate
 #[proc_macro]
 pub fn generate_geometric_product(input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(input as MacroArgs);
 
-    let dimension = match args.dimension.base10_parse::<usize>() {
-        Ok(dimension) => dimension,
-        Err(err) => {
-            return Error::new_spanned(args.dimension, err)
-                .to_compile_error()
-                .into()
-        }
+    let signature = match args.space.signature() {
+        Ok(signature) => signature,
+        Err(err) => return err.to_compile_error().into(),
     };
+    let dimension = signature.dimension();
 
     let function_ident = args.function_ident;
 
     let elements = generate_elements(dimension);
-    let product_sums = generate_product_sums(&elements);
+    let product_sums = generate_product_sums_with_signature(&elements, &signature);
     let product_string = generate_product_string(&product_sums);
 
     let array_length = elements.len();
@@ -213,8 +231,13 @@ pub fn generate_geometric_product(input: TokenStream) -> TokenStream {
 
     let basis = generate_base_string(&elements);
     let dimension = format!("{}", dimension);
+    let squares = match args.space {
+        Space::Dimension(_) => "-1 each".to_string(),
+        Space::Squares(_) => format!("{:?}", signature.squares()),
+    };
     let documentation = format!(
         "Calculates the geometric product for multivectors of {dimension}-dimensional space.
+        The basis vectors square to {squares}.
         The arrays are coefficient representations wrt. the following {array_length} basis
         elements.",
     );
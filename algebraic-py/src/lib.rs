@@ -0,0 +1,301 @@
+//! Python bindings for the multi vectors of [algebraic](https://docs.rs/algebraic/latest/algebraic).
+//!
+//! The Python module `algebraic` provides the classes `MultiVector1`, .., `MultiVector6`, they
+//! wrap the respective Rust types with [f64] coefficients. Build and install it locally with
+//! [maturin](https://www.maturin.rs):
+//!
+//! ```text
+//! cd algebraic-py
+//! maturin develop --release
+//! ```
+//!
+//! ```python
+//! import numpy as np
+//! from algebraic import MultiVector3
+//!
+//! a = MultiVector3([1.0, 2.0, 0.0, -0.5, 0.0, 0.0, 0.0, 3.0])
+//! b = MultiVector3.from_numpy(np.arange(8.0))
+//!
+//! print(a)                      # MultiVector3(1 + 2e1 - 0.5e12 + 3e123)
+//! print(a * b + 2.0 * a.reverse())
+//! print(np.asarray(a.exp()))
+//! ```
+use algebraic::{
+    MultiVector, MultiVector1, MultiVector2, MultiVector3, MultiVector4, MultiVector5,
+    MultiVector6, RingMultiVector,
+};
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::{
+    exceptions::{PyIndexError, PyValueError, PyZeroDivisionError},
+    prelude::*,
+};
+
+macro_rules! generate_py_multi_vector {
+    ($name:ident, $operand:ident, $multi_vector:ident, $python_name:literal) => {
+        #[doc = concat!("Python class wrapping [", stringify!($multi_vector), "] of [f64].")]
+        #[pyclass(name = $python_name, module = "algebraic", from_py_object)]
+        #[derive(Clone)]
+        pub struct $name(pub $multi_vector<f64>);
+
+        #[derive(FromPyObject)]
+        enum $operand<'py> {
+            MultiVector(PyRef<'py, $name>),
+            Scalar(f64),
+        }
+
+        impl $name {
+            const BASE_SIZE: usize = <$multi_vector<f64> as RingMultiVector<f64>>::BASE_SIZE;
+
+            fn from_coefficients(
+                coefficients: impl ExactSizeIterator<Item = f64>,
+            ) -> PyResult<Self> {
+                if coefficients.len() != Self::BASE_SIZE {
+                    return Err(PyValueError::new_err(format!(
+                        "expected {} coefficients, got {}",
+                        Self::BASE_SIZE,
+                        coefficients.len()
+                    )));
+                }
+                let mut multi_vector = $multi_vector::default();
+                for (i, c) in coefficients.enumerate() {
+                    multi_vector[i] = c;
+                }
+                Ok(Self(multi_vector))
+            }
+
+            // python style index, negative counts from the back
+            fn index(index: isize) -> PyResult<usize> {
+                let size = Self::BASE_SIZE as isize;
+                match index {
+                    i if (0..size).contains(&i) => Ok(i as usize),
+                    i if (-size..0).contains(&i) => Ok((size + i) as usize),
+                    _ => Err(PyIndexError::new_err("coefficient index out of range")),
+                }
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            #[classattr]
+            const ALGEBRA_DIMENSION: usize =
+                <$multi_vector<f64> as RingMultiVector<f64>>::ALGEBRA_DIMENSION;
+
+            #[classattr]
+            #[pyo3(name = "BASE_SIZE")]
+            fn base_size() -> usize {
+                Self::BASE_SIZE
+            }
+
+            /// Takes all coefficients (wrt. the generated base), zero if none are given.
+            #[new]
+            #[pyo3(signature = (coefficients = None))]
+            fn new(coefficients: Option<Vec<f64>>) -> PyResult<Self> {
+                match coefficients {
+                    Some(coefficients) => Self::from_coefficients(coefficients.into_iter()),
+                    None => Ok(Self($multi_vector::default())),
+                }
+            }
+
+            /// Takes the coefficients from a one-dimensional numpy array.
+            #[staticmethod]
+            fn from_numpy(array: PyReadonlyArray1<'_, f64>) -> PyResult<Self> {
+                Self::from_coefficients(array.as_array().iter().copied())
+            }
+
+            /// Copies the coefficients into a new numpy array.
+            fn to_numpy<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+                PyArray1::from_slice(py, self.0.as_ref())
+            }
+
+            #[pyo3(signature = (dtype = None, copy = None))]
+            fn __array__<'py>(
+                &self,
+                py: Python<'py>,
+                dtype: Option<Bound<'py, PyAny>>,
+                copy: Option<bool>,
+            ) -> PyResult<Bound<'py, PyAny>> {
+                if copy == Some(false) {
+                    return Err(PyValueError::new_err("the coefficients are always copied"));
+                }
+                let array = self.to_numpy(py).into_any();
+                match dtype {
+                    Some(dtype) => array.call_method1("astype", (dtype,)),
+                    None => Ok(array),
+                }
+            }
+
+            fn __repr__(&self) -> String {
+                format!("{}({})", $python_name, self.0)
+            }
+
+            fn __len__(&self) -> usize {
+                Self::BASE_SIZE
+            }
+
+            fn __getitem__(&self, index: isize) -> PyResult<f64> {
+                Ok(self.0[Self::index(index)?])
+            }
+
+            fn __setitem__(&mut self, index: isize, value: f64) -> PyResult<()> {
+                self.0[Self::index(index)?] = value;
+                Ok(())
+            }
+
+            fn __add__(&self, other: Self) -> Self {
+                Self(self.0 + other.0)
+            }
+
+            fn __sub__(&self, other: Self) -> Self {
+                Self(self.0 - other.0)
+            }
+
+            fn __neg__(&self) -> Self {
+                Self(self.0 * -1.0)
+            }
+
+            /// Geometric product with another multi vector, or scaling.
+            fn __mul__(&self, other: $operand<'_>) -> Self {
+                match other {
+                    $operand::MultiVector(other) => Self(self.0 * other.0),
+                    $operand::Scalar(scalar) => Self(self.0 * scalar),
+                }
+            }
+
+            fn __rmul__(&self, scalar: f64) -> Self {
+                Self(self.0 * scalar)
+            }
+
+            fn __truediv__(&self, scalar: f64) -> Self {
+                Self(self.0 / scalar)
+            }
+
+            fn reverse(&self) -> Self {
+                Self(self.0.reverse())
+            }
+
+            /// Raises `ZeroDivisionError` if there is no inverse.
+            fn inverse(&self) -> PyResult<Self> {
+                self.0
+                    .inverse()
+                    .map(Self)
+                    .ok_or_else(|| PyZeroDivisionError::new_err("multi vector is not invertible"))
+            }
+
+            fn exp(&self) -> Self {
+                Self(self.0.exp())
+            }
+        }
+    };
+}
+
+generate_py_multi_vector!(PyMultiVector1, Operand1, MultiVector1, "MultiVector1");
+generate_py_multi_vector!(PyMultiVector2, Operand2, MultiVector2, "MultiVector2");
+generate_py_multi_vector!(PyMultiVector3, Operand3, MultiVector3, "MultiVector3");
+generate_py_multi_vector!(PyMultiVector4, Operand4, MultiVector4, "MultiVector4");
+generate_py_multi_vector!(PyMultiVector5, Operand5, MultiVector5, "MultiVector5");
+generate_py_multi_vector!(PyMultiVector6, Operand6, MultiVector6, "MultiVector6");
+
+/// The Python module `algebraic`.
+#[pymodule]
+#[pyo3(name = "algebraic")]
+fn algebraic_py(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyMultiVector1>()?;
+    module.add_class::<PyMultiVector2>()?;
+    module.add_class::<PyMultiVector3>()?;
+    module.add_class::<PyMultiVector4>()?;
+    module.add_class::<PyMultiVector5>()?;
+    module.add_class::<PyMultiVector6>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    fn run(code: &str) {
+        Python::attach(|py| {
+            let module = PyModule::new(py, "algebraic").unwrap();
+            algebraic_py(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("algebraic", module).unwrap();
+            py.run(&std::ffi::CString::new(code).unwrap(), Some(&globals), None)
+                .map_err(|err| err.to_string())
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn numpy() {
+        if Python::attach(|py| py.import("numpy").is_err()) {
+            eprintln!("numpy isn't installed, skipping");
+            return;
+        }
+        run(r#"
+import numpy as np
+MultiVector3 = algebraic.MultiVector3
+
+a = MultiVector3.from_numpy(np.arange(8.0))
+assert list(a) == list(range(8))
+array = a.to_numpy()
+assert array.dtype == np.float64 and (array == np.arange(8.0)).all()
+assert (np.asarray(a) == np.arange(8.0)).all()
+assert np.asarray(a, dtype=np.float32).dtype == np.float32
+assert (np.array(a, copy=True) == np.arange(8.0)).all()
+
+try:
+    np.array(a, copy=False)
+    assert False
+except ValueError:
+    pass
+
+try:
+    MultiVector3.from_numpy(np.arange(4.0))
+    assert False
+except ValueError as err:
+    assert "expected 8 coefficients, got 4" in str(err), err
+"#);
+    }
+
+    #[test]
+    fn repr() {
+        run(r#"
+a = algebraic.MultiVector3([1.0, 2.0, 0.0, -0.5, 0.0, 0.0, 0.0, 3.0])
+assert repr(a) == "MultiVector3(1 + 2e1 - 0.5e12 + 3e123)", repr(a)
+assert repr(algebraic.MultiVector2()) == "MultiVector2(0)"
+"#);
+    }
+
+    #[test]
+    fn operators() {
+        run(r#"
+MultiVector3 = algebraic.MultiVector3
+a = MultiVector3([1., 2., 3., 4., 5., 6., 7., 8.])
+b = MultiVector3([8., 7., 6., 5., 4., 3., 2., 1.])
+assert list(a * b) == [-88.0, -18.0, 60.0, -18.0, 72.0, 102.0, -36.0, 114.0]
+assert list(a + b) == [9.0] * 8
+assert list(a - a) == [0.0] * 8
+assert list(2.0 * a) == list(a * 2.0) == list(a + a)
+assert list(a / 2.0) == list(a * 0.5)
+assert list(-a) == list(a * -1.0)
+assert list(a.reverse()) == [1., 2., 3., -4., 5., -6., -7., -8.]
+assert MultiVector3.ALGEBRA_DIMENSION == 3 and MultiVector3.BASE_SIZE == 8 and len(a) == 8
+assert a[-1] == 8.0
+
+one = a * a.inverse()
+assert abs(one[0] - 1.0) < 1e-10 and all(abs(one[i]) < 1e-10 for i in range(1, 8))
+
+try:
+    MultiVector3().inverse()
+    assert False
+except ZeroDivisionError:
+    pass
+
+try:
+    MultiVector3([1.0])
+    assert False
+except ValueError:
+    pass
+"#);
+    }
+}
@@ -0,0 +1,101 @@
+/* Generated by algebraic::ffi::header(), do not edit. */
+#ifndef ALGEBRAIC_H
+#define ALGEBRAIC_H
+
+#include <stdbool.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * The i-th coefficient belongs to the base element which is the product of the basis vectors
+ * corresponding to the set bits of i, e.g. coefficients[5] belongs to e1 e3.
+ * All basis vectors square to -1.
+ */
+
+typedef struct MultiVector2F32 {
+    float coefficients[4];
+} MultiVector2F32;
+
+MultiVector2F32 algebraic_multi_vector2_f32_product(MultiVector2F32 a, MultiVector2F32 b);
+MultiVector2F32 algebraic_multi_vector2_f32_add(MultiVector2F32 a, MultiVector2F32 b);
+MultiVector2F32 algebraic_multi_vector2_f32_reverse(MultiVector2F32 a);
+bool algebraic_multi_vector2_f32_inverse(MultiVector2F32 a, MultiVector2F32 *out);
+MultiVector2F32 algebraic_multi_vector2_f32_exp(MultiVector2F32 a);
+
+typedef struct MultiVector2F64 {
+    double coefficients[4];
+} MultiVector2F64;
+
+MultiVector2F64 algebraic_multi_vector2_f64_product(MultiVector2F64 a, MultiVector2F64 b);
+MultiVector2F64 algebraic_multi_vector2_f64_add(MultiVector2F64 a, MultiVector2F64 b);
+MultiVector2F64 algebraic_multi_vector2_f64_reverse(MultiVector2F64 a);
+bool algebraic_multi_vector2_f64_inverse(MultiVector2F64 a, MultiVector2F64 *out);
+MultiVector2F64 algebraic_multi_vector2_f64_exp(MultiVector2F64 a);
+
+typedef struct MultiVector3F32 {
+    float coefficients[8];
+} MultiVector3F32;
+
+MultiVector3F32 algebraic_multi_vector3_f32_product(MultiVector3F32 a, MultiVector3F32 b);
+MultiVector3F32 algebraic_multi_vector3_f32_add(MultiVector3F32 a, MultiVector3F32 b);
+MultiVector3F32 algebraic_multi_vector3_f32_reverse(MultiVector3F32 a);
+bool algebraic_multi_vector3_f32_inverse(MultiVector3F32 a, MultiVector3F32 *out);
+MultiVector3F32 algebraic_multi_vector3_f32_exp(MultiVector3F32 a);
+
+typedef struct MultiVector3F64 {
+    double coefficients[8];
+} MultiVector3F64;
+
+MultiVector3F64 algebraic_multi_vector3_f64_product(MultiVector3F64 a, MultiVector3F64 b);
+MultiVector3F64 algebraic_multi_vector3_f64_add(MultiVector3F64 a, MultiVector3F64 b);
+MultiVector3F64 algebraic_multi_vector3_f64_reverse(MultiVector3F64 a);
+bool algebraic_multi_vector3_f64_inverse(MultiVector3F64 a, MultiVector3F64 *out);
+MultiVector3F64 algebraic_multi_vector3_f64_exp(MultiVector3F64 a);
+
+typedef struct MultiVector4F32 {
+    float coefficients[16];
+} MultiVector4F32;
+
+MultiVector4F32 algebraic_multi_vector4_f32_product(MultiVector4F32 a, MultiVector4F32 b);
+MultiVector4F32 algebraic_multi_vector4_f32_add(MultiVector4F32 a, MultiVector4F32 b);
+MultiVector4F32 algebraic_multi_vector4_f32_reverse(MultiVector4F32 a);
+bool algebraic_multi_vector4_f32_inverse(MultiVector4F32 a, MultiVector4F32 *out);
+MultiVector4F32 algebraic_multi_vector4_f32_exp(MultiVector4F32 a);
+
+typedef struct MultiVector4F64 {
+    double coefficients[16];
+} MultiVector4F64;
+
+MultiVector4F64 algebraic_multi_vector4_f64_product(MultiVector4F64 a, MultiVector4F64 b);
+MultiVector4F64 algebraic_multi_vector4_f64_add(MultiVector4F64 a, MultiVector4F64 b);
+MultiVector4F64 algebraic_multi_vector4_f64_reverse(MultiVector4F64 a);
+bool algebraic_multi_vector4_f64_inverse(MultiVector4F64 a, MultiVector4F64 *out);
+MultiVector4F64 algebraic_multi_vector4_f64_exp(MultiVector4F64 a);
+
+typedef struct MultiVector5F32 {
+    float coefficients[32];
+} MultiVector5F32;
+
+MultiVector5F32 algebraic_multi_vector5_f32_product(MultiVector5F32 a, MultiVector5F32 b);
+MultiVector5F32 algebraic_multi_vector5_f32_add(MultiVector5F32 a, MultiVector5F32 b);
+MultiVector5F32 algebraic_multi_vector5_f32_reverse(MultiVector5F32 a);
+bool algebraic_multi_vector5_f32_inverse(MultiVector5F32 a, MultiVector5F32 *out);
+MultiVector5F32 algebraic_multi_vector5_f32_exp(MultiVector5F32 a);
+
+typedef struct MultiVector5F64 {
+    double coefficients[32];
+} MultiVector5F64;
+
+MultiVector5F64 algebraic_multi_vector5_f64_product(MultiVector5F64 a, MultiVector5F64 b);
+MultiVector5F64 algebraic_multi_vector5_f64_add(MultiVector5F64 a, MultiVector5F64 b);
+MultiVector5F64 algebraic_multi_vector5_f64_reverse(MultiVector5F64 a);
+bool algebraic_multi_vector5_f64_inverse(MultiVector5F64 a, MultiVector5F64 *out);
+MultiVector5F64 algebraic_multi_vector5_f64_exp(MultiVector5F64 a);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ALGEBRAIC_H */
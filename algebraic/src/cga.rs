@@ -0,0 +1,234 @@
+//! Conformal geometric algebra (CGA) of 3D space, i.e. `Cl(4,1)`, for spheres, circles, and point
+//! pairs.
+//!
+//! Everything is a [CgaMultiVector] and uses its geometric product. The basis vectors `e1`, `e2`,
+//! and `e3` span Euclidean space, `e4` and `e5` (squaring to `1` and `-1`) make up the
+//! [origin](CgaMultiVector::origin) `(e5 - e4) / 2` and [infinity](CgaMultiVector::infinity)
+//! `e4 + e5`, both null vectors. Euclidean points are embedded as null vectors with
+//! [up](CgaMultiVector::up), and recovered with [down](CgaMultiVector::down).
+//!
+//! Objects are in their dual (inner product null space) form, i.e. the point `x` lies on `X` if
+//! `up(x).inner(X)` is zero. This way, the [meet](CgaMultiVector::meet), i.e. the outer product,
+//! intersects: two spheres meet in a circle, three in a point pair. The
+//! [center](CgaMultiVector::center) and [radius](CgaMultiVector::radius) of such round objects
+//! are extracted from the blades.
+//!
+//! ```
+//! # use algebraic::CgaMultiVector;
+//! let a = CgaMultiVector::sphere([0.0, 0.0, 0.0], 5.0);
+//! let b = CgaMultiVector::sphere([6.0, 0.0, 0.0], 5.0);
+//! let circle = a.meet(b);
+//! assert_eq!(circle.center(), Some([3.0, 0.0, 0.0]));
+//! assert_eq!(circle.radius(), Some(4.0));
+//! ```
+use crate::{
+    multi_vector::{grade_part, inner_product, outer_product},
+    CgaMultiVector, RingMultiVector, Scalar,
+};
+
+// indices of the basis vectors
+const E1: usize = 0b00001;
+const E2: usize = 0b00010;
+const E3: usize = 0b00100;
+const E4: usize = 0b01000;
+const E5: usize = 0b10000;
+const PSEUDOSCALAR: usize = 0b11111;
+
+impl<T: Scalar<Real = T>> CgaMultiVector<T> {
+    fn from_coefficients(coefficients: impl IntoIterator<Item = (usize, T)>) -> Self {
+        let mut a = Self::default();
+        for (i, x) in coefficients {
+            a[i] = x;
+        }
+        a
+    }
+
+    fn euclidean([x, y, z]: [T; 3]) -> Self {
+        Self::from_coefficients([(E1, x), (E2, y), (E3, z)])
+    }
+
+    /// The null vector `(e5 - e4) / 2`, the embedded origin.
+    pub fn origin() -> Self {
+        let half = T::from_f64(0.5);
+        Self::from_coefficients([(E4, -half), (E5, half)])
+    }
+
+    /// The null vector `e4 + e5`, the point at infinity.
+    pub fn infinity() -> Self {
+        Self::from_coefficients([(E4, T::one()), (E5, T::one())])
+    }
+
+    /// Embeds the Euclidean point `x` as the null vector `x + x²/2 infinity + origin`.
+    pub fn up(x: [T; 3]) -> Self {
+        let x = Self::euclidean(x);
+        let square = (x * x)[0];
+        x + Self::infinity() * (square * T::from_f64(0.5)) + Self::origin()
+    }
+
+    /// The Euclidean point of a (scaled) null vector, `None` if it has no origin component, e.g.
+    /// for infinity.
+    pub fn down(self) -> Option<[T; 3]> {
+        // the coefficient of the origin, `-self.inner(infinity)`
+        let w = self[E5] - self[E4];
+        (w != T::zero()).then(|| [self[E1] / w, self[E2] / w, self[E3] / w])
+    }
+
+    /// The sphere around `center` with the given `radius`.
+    pub fn sphere(center: [T; 3], radius: T) -> Self {
+        Self::up(center) - Self::infinity() * (radius * radius * T::from_f64(0.5))
+    }
+
+    /// The plane `ax + by + cz + d = 0`.
+    pub fn plane(a: T, b: T, c: T, d: T) -> Self {
+        Self::euclidean([a, b, c]) - Self::infinity() * d
+    }
+
+    /// The circle around `center` in the plane with the given `normal`.
+    pub fn circle(center: [T; 3], normal: [T; 3], radius: T) -> Self {
+        let [a, b, c] = normal;
+        let d = -(Self::euclidean(normal) * Self::euclidean(center))[0];
+        Self::sphere(center, radius).meet(Self::plane(a, b, c, d))
+    }
+
+    /// The pair of the points `a` and `b`.
+    pub fn point_pair(a: [T; 3], b: [T; 3]) -> Self {
+        outer_product(Self::up(a), Self::up(b)).dual()
+    }
+
+    /// The line through `point` in `direction`.
+    pub fn line(point: [T; 3], direction: [T; 3]) -> Self {
+        let [x, y, z] = point;
+        let [dx, dy, dz] = direction;
+        let direct = outer_product(Self::up(point), Self::up([x + dx, y + dy, z + dz]));
+        outer_product(direct, Self::infinity()).dual()
+    }
+
+    /// The outer product, i.e. the intersection of the (dual) objects.
+    pub fn meet(self, other: Self) -> Self {
+        outer_product(self, other)
+    }
+
+    /// The symmetric inner product, e.g. zero for points on an object.
+    pub fn inner(self, other: Self) -> Self {
+        inner_product(self, other)
+    }
+
+    /// `self / pseudoscalar`, switches between direct (outer product null space) and dual forms.
+    pub fn dual(self) -> Self {
+        // the pseudoscalar squares to `-1`
+        self * Self::from_coefficients([(PSEUDOSCALAR, -T::one())])
+    }
+
+    /// The center of a round object, i.e. a sphere, circle, or point pair, `None` for flat
+    /// objects.
+    pub fn center(self) -> Option<[T; 3]> {
+        grade_part(self * Self::infinity() * self, 1).down()
+    }
+
+    /// The squared radius of a round object, negative for imaginary ones, e.g. the circle where
+    /// two disjoint spheres meet.
+    pub fn radius_squared(self) -> T {
+        let weight = Self::infinity().inner(self);
+        (self * self.reverse())[0] / (weight * weight.reverse())[0]
+    }
+
+    /// The radius of a round object, `None` for imaginary ones.
+    pub fn radius(self) -> Option<T>
+    where
+        T: PartialOrd,
+    {
+        let radius_squared = self.radius_squared();
+        (radius_squared >= T::zero()).then(|| radius_squared.sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Cga = CgaMultiVector<f64>;
+
+    fn close(a: [f64; 3], b: [f64; 3]) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-12)
+    }
+
+    fn contains(object: Cga, point: [f64; 3]) -> bool {
+        Cga::up(point)
+            .inner(object)
+            .into_iter()
+            .all(|x| x.abs() < 1e-12)
+    }
+
+    #[test]
+    fn embedding() {
+        let (x, y) = ([1.0, 2.0, 3.0], [4.0, 6.0, 3.0]);
+        assert_eq!(Cga::up(x).down(), Some(x));
+        assert_eq!((Cga::up(x) * 3.0).down(), Some(x));
+        assert_eq!(Cga::infinity().down(), None);
+        assert_eq!((Cga::up(x) * Cga::up(x))[0], 0.0);
+        // the inner product of points is their squared distance
+        assert_eq!(Cga::up(x).inner(Cga::up(y))[0], -12.5);
+        assert_eq!(Cga::origin().inner(Cga::infinity())[0], -1.0);
+    }
+
+    #[test]
+    fn rounds() {
+        let sphere = Cga::sphere([1.0, 2.0, 3.0], 2.0);
+        assert!(contains(sphere, [1.0, 2.0, 5.0]));
+        assert!(!contains(sphere, [1.0, 2.0, 4.0]));
+        assert!(close(sphere.center().unwrap(), [1.0, 2.0, 3.0]));
+        assert!((sphere.radius().unwrap() - 2.0).abs() < 1e-12);
+
+        let circle = Cga::circle([1.0, 0.0, 0.0], [0.0, 0.0, 1.0], 3.0);
+        assert!(contains(circle, [1.0, 3.0, 0.0]));
+        assert!(!contains(circle, [1.0, 0.0, 3.0]));
+        assert!(close(circle.center().unwrap(), [1.0, 0.0, 0.0]));
+        assert!((circle.radius().unwrap() - 3.0).abs() < 1e-12);
+
+        let pair = Cga::point_pair([1.0, 1.0, 1.0], [3.0, 1.0, 1.0]);
+        assert!(contains(pair, [1.0, 1.0, 1.0]) && contains(pair, [3.0, 1.0, 1.0]));
+        assert!(close(pair.center().unwrap(), [2.0, 1.0, 1.0]));
+        assert!((pair.radius().unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn flats() {
+        let plane = Cga::plane(0.0, 0.0, 2.0, -2.0);
+        assert!(contains(plane, [5.0, -3.0, 1.0]));
+        assert!(!contains(plane, [5.0, -3.0, 0.0]));
+
+        let line = Cga::line([1.0, 2.0, 3.0], [0.0, 1.0, 0.0]);
+        assert!(contains(line, [1.0, 5.0, 3.0]));
+        assert!(!contains(line, [1.0, 5.0, 4.0]));
+        assert_eq!(line.center(), None);
+        assert_eq!(plane.center(), None);
+    }
+
+    #[test]
+    fn intersections() {
+        let a = Cga::sphere([0.0, 0.0, 0.0], 5.0);
+        let b = Cga::sphere([6.0, 0.0, 0.0], 5.0);
+        let circle = a.meet(b);
+        assert!(contains(circle, [3.0, 0.0, 4.0]) && contains(circle, [3.0, -4.0, 0.0]));
+        assert!(close(circle.center().unwrap(), [3.0, 0.0, 0.0]));
+        assert!((circle.radius().unwrap() - 4.0).abs() < 1e-12);
+
+        let c = Cga::sphere([3.0, 3.0, 0.0], 5.0);
+        let pair = circle.meet(c);
+        assert!(contains(pair, [3.0, 0.0, 4.0]) && contains(pair, [3.0, 0.0, -4.0]));
+        assert!(close(pair.center().unwrap(), [3.0, 0.0, 0.0]));
+
+        let line = Cga::line([-10.0, 0.0, 0.0], [1.0, 0.0, 0.0]);
+        let pair = line.meet(a);
+        assert!(contains(pair, [-5.0, 0.0, 0.0]) && contains(pair, [5.0, 0.0, 0.0]));
+        assert!((pair.radius().unwrap() - 5.0).abs() < 1e-12);
+
+        let plane = Cga::plane(1.0, 0.0, 0.0, -3.0);
+        assert!((a.meet(plane).radius().unwrap() - 4.0).abs() < 1e-12);
+
+        // disjoint spheres meet in an imaginary circle
+        let far = Cga::sphere([20.0, 0.0, 0.0], 5.0);
+        assert!(a.meet(far).radius_squared() < 0.0);
+        assert_eq!(a.meet(far).radius(), None);
+    }
+}
@@ -0,0 +1,207 @@
+//! Multi vectors over the complex numbers, e.g. the Dirac algebra. (Only with the `num-complex`
+//! feature.)
+//!
+//! [Complex] is a [Scalar], so e.g. `MultiVector4<Complex<f64>>` implements [MultiVector] with
+//! the [norm](MultiVector::norm) being the Hermitian one. [ComplexMultiVector] adds the
+//! operations specific to complex coefficients.
+//!
+//! ```
+//! # use algebraic::{complex::ComplexMultiVector, MultiVector, MultiVector4};
+//! # use num_complex::Complex;
+//! // the Dirac matrices, with signature (+, -, -, -)
+//! let i = Complex::i();
+//! let gamma = [
+//!     MultiVector4::base_element(1) * i,
+//!     MultiVector4::base_element(2),
+//!     MultiVector4::base_element(4),
+//!     MultiVector4::base_element(8),
+//! ];
+//! assert_eq!(gamma[0] * gamma[0], MultiVector4::<Complex<f64>>::base_element(0));
+//! assert_eq!(gamma[0].hermitian_conjugate(), gamma[0] * -Complex::from(1.0));
+//! ```
+use crate::{MultiVector, Scalar};
+use num_complex::Complex;
+use num_traits::Float;
+
+/// With [Scalar::Real] `T`. [Scalar::atan2] is continued analytically, i.e.
+/// `-i ln((x + iy) / √(x² + y²))`.
+impl<T: Scalar<Real = T> + Float> Scalar for Complex<T> {
+    type Real = T;
+
+    fn zero() -> Self {
+        Complex::new(<T as Scalar>::zero(), <T as Scalar>::zero())
+    }
+
+    fn one() -> Self {
+        Complex::new(<T as Scalar>::one(), <T as Scalar>::zero())
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Complex::new(T::from_f64(value), <T as Scalar>::zero())
+    }
+
+    fn abs(self) -> T {
+        self.norm()
+    }
+
+    fn sqrt(self) -> Self {
+        Complex::sqrt(self)
+    }
+
+    fn exp(self) -> Self {
+        Complex::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        Complex::ln(self)
+    }
+
+    fn sin(self) -> Self {
+        Complex::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        Complex::cos(self)
+    }
+
+    fn acos(self) -> Self {
+        Complex::acos(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self, other);
+        let i = Complex::<T>::i();
+        -i * ((x + i * y) / Complex::sqrt(x * x + y * y)).ln()
+    }
+
+    fn sinh(self) -> Self {
+        Complex::sinh(self)
+    }
+
+    fn cosh(self) -> Self {
+        Complex::cosh(self)
+    }
+}
+
+/// Operations specific to [MultiVector]s over the complex numbers.
+pub trait ComplexMultiVector<T: Scalar<Real = T> + Float>: MultiVector<Complex<T>> {
+    /// Conjugates each coefficient.
+    fn conjugate(self) -> Self {
+        let mut conjugate = self;
+        for i in 0..Self::BASE_SIZE {
+            conjugate[i] = self[i].conj();
+        }
+        conjugate
+    }
+
+    /// The [reverse](crate::RingMultiVector::reverse) combined with the complex
+    /// [conjugate](ComplexMultiVector::conjugate).
+    fn hermitian_conjugate(self) -> Self {
+        self.reverse().conjugate()
+    }
+
+    /// The [norm](MultiVector::norm) of real multi vectors continued to complex coefficients, i.e.
+    /// the (principal) square root of the sum of squares, without conjugation.
+    fn complexified_norm(self) -> Complex<T> {
+        self.into_iter()
+            .fold(<Complex<T> as Scalar>::zero(), |sum, x| sum + x * x)
+            .sqrt()
+    }
+}
+
+impl<T: Scalar<Real = T> + Float, M: MultiVector<Complex<T>>> ComplexMultiVector<T> for M {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiVector2, MultiVector3, MultiVector4};
+
+    type C = Complex<f64>;
+
+    fn close(a: impl AsRef<[C]>, b: impl AsRef<[C]>) -> bool {
+        a.as_ref()
+            .iter()
+            .zip(b.as_ref())
+            .all(|(a, b)| (a - b).norm() < 1e-10)
+    }
+
+    fn sample() -> MultiVector3<C> {
+        MultiVector3(std::array::from_fn(|i| {
+            C::new(i as f64 * 0.1 - 0.3, 0.2 - i as f64 * 0.05)
+        }))
+    }
+
+    #[test]
+    fn dirac_algebra() {
+        let i = C::i();
+        let gamma = [
+            MultiVector4::base_element(1) * i,
+            MultiVector4::base_element(2),
+            MultiVector4::base_element(4),
+            MultiVector4::base_element(8),
+        ];
+        let metric = [1.0, -1.0, -1.0, -1.0];
+        for (m, &a) in gamma.iter().enumerate() {
+            for (n, &b) in gamma.iter().enumerate() {
+                let anticommutator = a * b + b * a;
+                let expected = if m == n { 2.0 * metric[m] } else { 0.0 };
+                assert_eq!(
+                    anticommutator,
+                    MultiVector4::base_element(0) * C::from(expected)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn multi_vector_methods() {
+        let a = sample();
+        assert!(close(
+            a * a.inverse().unwrap(),
+            MultiVector3::base_element(0)
+        ));
+        assert!(close(
+            a.exp() * (a * C::from(-1.0)).exp(),
+            MultiVector3::base_element(0)
+        ));
+
+        // exp(z e12) == cos(z) + e12 sin(z) as e12 squares to -1
+        let z = C::new(0.3, -1.2);
+        let e12 = MultiVector2::<C>::base_element(3);
+        assert!(close(
+            (e12 * z).exp(),
+            MultiVector2::base_element(0) * z.cos() + e12 * z.sin()
+        ));
+
+        let norm = a.into_iter().map(|c| c.norm_sqr()).sum::<f64>().sqrt();
+        assert!((a.norm() - norm).abs() < 1e-12);
+        let b = MultiVector2([
+            C::from(1.0),
+            C::new(0.0, -1.0),
+            C::from(0.0),
+            C::new(-2.0, 3.0),
+        ]);
+        assert_eq!(b.to_string(), "(1+0i) + (0-1i)e1 + (-2+3i)e12");
+        assert_eq!(b.to_string().parse(), Ok(b));
+    }
+
+    #[test]
+    fn complex_operations() {
+        let a = sample();
+        let b = a.exp();
+        assert!(close(
+            (a * b).hermitian_conjugate(),
+            b.hermitian_conjugate() * a.hermitian_conjugate()
+        ));
+        assert_eq!(a.conjugate().conjugate(), a);
+
+        let i = C::i();
+        let real = MultiVector2([3.0, 0.0, 4.0, 0.0].map(C::from));
+        assert_eq!(real.complexified_norm(), C::from(5.0));
+        assert_eq!((real * i).complexified_norm(), i * 5.0);
+        assert_eq!((real * i).norm(), 5.0);
+
+        let (x, y) = (C::from(1.0), C::from(1.0));
+        assert!((y.atan2(x) - C::from(std::f64::consts::FRAC_PI_4)).norm() < 1e-15);
+    }
+}
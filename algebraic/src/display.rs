@@ -0,0 +1,169 @@
+//! Formatting of multi vectors as sums of blades, e.g. `1 + 2e1 - 0.5e12 + 3e123`.
+//!
+//! The blades are named like the base elements in the documentation of the generated products,
+//! see [blade_name]. Coefficients other than plain numbers, e.g. complex numbers or symbols, are
+//! put into parentheses, like [parse](crate::parse) expects them.
+use algebraic_core::blade_name;
+use std::fmt::{Display, Formatter, Result};
+
+/// The name of the blade at the given coefficient index, e.g. `e13` for index `5` (`0b101`).
+/// The scalar is named `1`.
+pub fn index_blade_name(index: usize) -> String {
+    let element = (0..usize::BITS as usize)
+        .filter(|d| index & 1 << d != 0)
+        .collect::<Vec<_>>();
+    blade_name(&element)
+}
+
+const SUBSCRIPTS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+// (grade, negative, term without sign)
+type Term = (usize, bool, String);
+
+fn join(terms: &[Term]) -> String {
+    terms
+        .iter()
+        .enumerate()
+        .map(|(i, (_, negative, term))| match (i, negative) {
+            (0, false) => term.clone(),
+            (0, true) => format!("-{term}"),
+            (_, false) => format!(" + {term}"),
+            (_, true) => format!(" - {term}"),
+        })
+        .collect()
+}
+
+/// Writes the non-zero coefficients with their blade names, see the [Display] implementations.
+pub(crate) fn format_blades<T: Display + Default + PartialEq>(
+    coefficients: &[T],
+    f: &mut Formatter<'_>,
+) -> Result {
+    let format = |coefficient: &T| match f.precision() {
+        Some(precision) => format!("{coefficient:.precision$}"),
+        None => format!("{coefficient}"),
+    };
+
+    let zero = T::default();
+    let terms = coefficients
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| **c != zero)
+        .map(|(i, c)| {
+            let coefficient = format(c);
+            let (negative, magnitude) = match coefficient.strip_prefix('-') {
+                // e.g. complex numbers, keep them together including the sign
+                _ if coefficient
+                    .chars()
+                    .skip(1)
+                    .any(|c| matches!(c, '+' | '-' | ' ')) =>
+                {
+                    (false, format!("({coefficient})"))
+                }
+                Some(magnitude) => (true, magnitude.to_string()),
+                None => (false, coefficient),
+            };
+            // e.g. the symbol `a2` or `inf`, which would run into the blade name
+            let magnitude = if i != 0
+                && !magnitude.starts_with('(')
+                && !magnitude.chars().all(|c| c.is_ascii_digit() || c == '.')
+            {
+                format!("({magnitude})")
+            } else {
+                magnitude
+            };
+            let blade = if i == 0 {
+                String::new()
+            } else {
+                index_blade_name(i)
+            };
+            (i.count_ones() as usize, negative, magnitude + &blade)
+        })
+        .collect::<Vec<Term>>();
+
+    if terms.is_empty() {
+        return write!(f, "{}", format(&zero));
+    }
+    if !f.alternate() {
+        return write!(f, "{}", join(&terms));
+    }
+
+    let dimension = coefficients.len().trailing_zeros() as usize;
+    let groups = (0..=dimension)
+        .filter_map(|grade| {
+            let group = terms
+                .iter()
+                .filter(|(g, _, _)| *g == grade)
+                .cloned()
+                .collect::<Vec<_>>();
+            let subscript = grade
+                .to_string()
+                .chars()
+                .map(|digit| SUBSCRIPTS[digit.to_digit(10).unwrap() as usize])
+                .collect::<String>();
+            (!group.is_empty()).then(|| format!("⟨{}⟩{subscript}", join(&group)))
+        })
+        .collect::<Vec<_>>();
+    write!(f, "{}", groups.join(" + "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiVector0, MultiVector2, MultiVector3, MultiVector4};
+
+    #[test]
+    fn blades() {
+        let a = MultiVector3([1.0, 2.0, 0.0, -0.5, 0.0, 0.0, 0.0, 3.0]);
+        assert_eq!(a.to_string(), "1 + 2e1 - 0.5e12 + 3e123");
+        assert_eq!(format!("{a:.2}"), "1.00 + 2.00e1 - 0.50e12 + 3.00e123");
+        assert_eq!(MultiVector2([0.0, -1.0, 0.0, 0.0]).to_string(), "-1e1");
+        assert_eq!(MultiVector4([0; 16]).to_string(), "0");
+        assert_eq!(format!("{:.1}", MultiVector0([0.0])), "0.0");
+        assert_eq!(index_blade_name(0), "1");
+        assert_eq!(index_blade_name(0b1010), "e24");
+    }
+
+    #[test]
+    fn grades() {
+        let a = MultiVector3([1.0, 2.0, 3.0, -0.5, 0.0, 0.0, 4.0, 3.0]);
+        assert_eq!(
+            format!("{a:#}"),
+            "⟨1⟩₀ + ⟨2e1 + 3e2⟩₁ + ⟨-0.5e12 + 4e23⟩₂ + ⟨3e123⟩₃"
+        );
+        assert_eq!(format!("{:#}", MultiVector2([0, 0, 0, 0])), "0");
+    }
+
+    #[test]
+    fn compound_coefficients() {
+        // stand-in for e.g. complex numbers
+        #[derive(Default, PartialEq)]
+        struct Pair(i32, i32);
+        impl Display for Pair {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                write!(f, "{}+{}i", self.0, self.1)
+            }
+        }
+        let a = MultiVector2([Pair(0, 0), Pair(1, 2), Pair(-3, 1), Pair(0, 0)]);
+        assert_eq!(a.to_string(), "(1+2i)e1 + (-3+1i)e2");
+
+        // anything but a number is kept apart from the blade name
+        struct Name(&'static str);
+        impl Display for Name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                f.write_str(self.0)
+            }
+        }
+        impl Default for Name {
+            fn default() -> Self {
+                Self("0")
+            }
+        }
+        impl PartialEq for Name {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        let a = MultiVector2([Name("a0"), Name("-a1"), Name("1/2"), Name("inf")]);
+        assert_eq!(a.to_string(), "a0 - (a1)e1 + (1/2)e2 + (inf)e12");
+    }
+}
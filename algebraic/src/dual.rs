@@ -0,0 +1,314 @@
+//! This module contains [Dual] numbers for forward-mode automatic differentiation, and the
+//! [jacobian] and [gradient] helpers built on them.
+//!
+//! [Dual] is a [Scalar], so multi vectors of dual numbers support everything multi vectors of
+//! real numbers do, including [inverse](crate::MultiVector::inverse) and
+//! [exp](crate::MultiVector::exp). Each coefficient carries its derivative along one direction.
+//!
+//! ```
+//! # use algebraic::{dual::jacobian, MultiVector, MultiVector2};
+//! // d(a * a)/da at a = e1 + e2
+//! let jacobian = jacobian(|a: MultiVector2<_>| a * a, &MultiVector2([0.0, 1.0, 1.0, 0.0]));
+//! assert_eq!(jacobian[0], [0.0, -2.0, -2.0, 0.0]);
+//! ```
+use crate::{RingMultiVector, Scalar};
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A value and its derivative along some direction, i.e. `value + derivative ε` with `ε² = 0`.
+///
+/// Ordering and equality compare the value first, then the derivative.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Dual<T> {
+    pub value: T,
+    pub derivative: T,
+}
+
+impl<T: Scalar> Dual<T> {
+    pub fn new(value: T, derivative: T) -> Self {
+        Self { value, derivative }
+    }
+
+    /// Derivative `0`.
+    pub fn constant(value: T) -> Self {
+        Self::new(value, T::zero())
+    }
+
+    /// Derivative `1`, i.e. the variable to differentiate with respect to.
+    pub fn variable(value: T) -> Self {
+        Self::new(value, T::one())
+    }
+
+    // chain rule: f(value) with f'(value) = `slope`
+    fn chain(self, value: T, slope: T) -> Self {
+        Self::new(value, self.derivative * slope)
+    }
+}
+
+impl<T: Scalar> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl<T: Scalar> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl<T: Scalar> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            self.value * rhs.derivative + self.derivative * rhs.value,
+        )
+    }
+}
+
+impl<T: Scalar> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.value / rhs.value,
+            (self.derivative * rhs.value - self.value * rhs.derivative) / (rhs.value * rhs.value),
+        )
+    }
+}
+
+impl<T: Scalar> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, -self.derivative)
+    }
+}
+
+/// Over real numbers only.
+impl<T: Scalar<Real = T> + PartialOrd> Scalar for Dual<T> {
+    type Real = Self;
+
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Self::constant(T::from_f64(value))
+    }
+
+    fn abs(self) -> Self {
+        if self.value < T::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        let sqrt = self.value.sqrt();
+        self.chain(sqrt, T::one() / (sqrt + sqrt))
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp)
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.value.ln(), T::one() / self.value)
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    fn acos(self) -> Self {
+        let slope = -T::one() / (T::one() - self.value * self.value).sqrt();
+        self.chain(self.value.acos(), slope)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self, other);
+        Self::new(
+            y.value.atan2(x.value),
+            (x.value * y.derivative - y.value * x.derivative)
+                / (x.value * x.value + y.value * y.value),
+        )
+    }
+
+    fn sinh(self) -> Self {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+
+    fn cosh(self) -> Self {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+}
+
+/// E.g. `1+0.5ε`, precision applies to both parts.
+impl<T: Display> Display for Dual<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "{:.precision$}+{:.precision$}ε",
+                self.value, self.derivative
+            ),
+            None => write!(f, "{}+{}ε", self.value, self.derivative),
+        }
+    }
+}
+
+// the coefficients as constants, except the `variable`-th one
+fn seed<T: Scalar, A: RingMultiVector<Dual<T>>>(at: &[T], variable: usize) -> A {
+    assert_eq!(
+        at.len(),
+        A::BASE_SIZE,
+        "expected {} coefficients to differentiate at",
+        A::BASE_SIZE
+    );
+    let mut a = A::default();
+    for (i, x) in at.iter().enumerate() {
+        a[i] = if i == variable {
+            Dual::variable(*x)
+        } else {
+            Dual::constant(*x)
+        };
+    }
+    a
+}
+
+/// The Jacobian of `f` at `at`, i.e. `jacobian[i][j]` is the derivative of the `i`-th output
+/// coefficient with respect to the `j`-th input coefficient.
+///
+/// Evaluates `f` once per input coefficient, each time with that coefficient as the
+/// [variable](Dual::variable).
+///
+/// # Panics
+///
+/// If `at` doesn't have exactly `A::BASE_SIZE` coefficients.
+pub fn jacobian<T, A, B>(f: impl Fn(A) -> B, at: &impl AsRef<[T]>) -> Vec<Vec<T>>
+where
+    T: Scalar,
+    A: RingMultiVector<Dual<T>>,
+    B: RingMultiVector<Dual<T>>,
+{
+    let columns = (0..A::BASE_SIZE)
+        .map(|j| {
+            f(seed::<T, A>(at.as_ref(), j))
+                .into_iter()
+                .map(|y| y.derivative)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    (0..B::BASE_SIZE)
+        .map(|i| columns.iter().map(|column| column[i]).collect())
+        .collect()
+}
+
+/// The gradient of the scalar function `f` at `at`, i.e. the derivatives with respect to each
+/// coefficient, see [jacobian].
+///
+/// # Panics
+///
+/// If `at` doesn't have exactly `A::BASE_SIZE` coefficients.
+pub fn gradient<T, A>(f: impl Fn(A) -> Dual<T>, at: &impl AsRef<[T]>) -> Vec<T>
+where
+    T: Scalar,
+    A: RingMultiVector<Dual<T>>,
+{
+    (0..A::BASE_SIZE)
+        .map(|j| f(seed(at.as_ref(), j)).derivative)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiVector, MultiVector3};
+
+    fn close(a: &[f64], b: &[f64]) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-6)
+    }
+
+    #[test]
+    fn scalar_functions() {
+        let x = Dual::variable(0.3);
+        let h = 1e-7;
+        for (f, g) in [
+            (
+                Scalar::sqrt as fn(Dual<f64>) -> Dual<f64>,
+                f64::sqrt as fn(f64) -> f64,
+            ),
+            (Scalar::exp, f64::exp),
+            (Scalar::ln, f64::ln),
+            (Scalar::sin, f64::sin),
+            (Scalar::cos, f64::cos),
+            (Scalar::acos, f64::acos),
+            (Scalar::sinh, f64::sinh),
+            (Scalar::cosh, f64::cosh),
+            (|x| x / (x * x + Dual::one()), |x| x / (x * x + 1.0)),
+            (|x| x.atan2(Dual::constant(2.0)), |x| x.atan2(2.0)),
+        ] {
+            let difference = (g(0.3 + h) - g(0.3 - h)) / (2.0 * h);
+            assert_eq!(f(x).value, g(0.3));
+            assert!((f(x).derivative - difference).abs() < 1e-6);
+        }
+        assert_eq!(format!("{:.1}", Dual::new(1.0, 0.5)), "1.0+0.5ε");
+    }
+
+    #[test]
+    fn jacobian_product() {
+        let a = MultiVector3([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let jacobian = jacobian(|x: MultiVector3<_>| x * x, &a);
+        // d(x * x)/dx_j = e_j * a + a * e_j
+        for j in 0..8 {
+            let e = MultiVector3::base_element(j);
+            let column = jacobian.iter().map(|row| row[j]).collect::<Vec<_>>();
+            assert_eq!(column, (e * a + a * e).0);
+        }
+    }
+
+    #[test]
+    fn jacobian_exp() {
+        let a = MultiVector3([0.1, 0.2, -0.3, 0.4, 0.5, -0.6, 0.7, 0.8]);
+        let jacobian = jacobian(|x: MultiVector3<_>| x.exp(), &a);
+        let h = 1e-6;
+        for j in 0..8 {
+            let e = MultiVector3::base_element(j) * h;
+            let difference = ((a + e).exp() - (a - e).exp()) / (2.0 * h);
+            let column = jacobian.iter().map(|row| row[j]).collect::<Vec<_>>();
+            assert!(close(&column, &difference.0), "{column:?} {difference:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 8 coefficients")]
+    fn wrong_length() {
+        gradient(|x: MultiVector3<_>| x.norm(), &[1.0; 4]);
+    }
+
+    #[test]
+    fn gradient_norm() {
+        let a = MultiVector3([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let gradient = gradient(|x: MultiVector3<_>| x.norm(), &a);
+        assert!(close(&gradient, &(a / a.norm()).0));
+    }
+}
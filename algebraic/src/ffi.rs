@@ -0,0 +1,318 @@
+//! C-compatible layer for [MultiVector2], .., [MultiVector5] with [f32] and [f64] coefficients.
+//! (Only with the `ffi` feature.)
+//!
+//! Each combination has a `#[repr(C)]` struct, e.g. [MultiVector3F64], and `extern "C"` functions
+//! for the geometric product, addition, [reverse](RingMultiVector::reverse),
+//! [inverse](MultiVector::inverse), and [exponential](MultiVector::exp), e.g.
+//! [algebraic_multi_vector3_f64_product].
+//!
+//! The accompanying C header is `include/algebraic.h`, it is generated by [header] and tested to
+//! be up to date. To link from C or C++, build e.g. a static library:
+//!
+//! ```text
+//! cargo rustc -p algebraic --release --features ffi --crate-type staticlib
+//! ```
+use crate::{MultiVector, MultiVector2, MultiVector3, MultiVector4, MultiVector5, RingMultiVector};
+
+struct FfiType {
+    name: &'static str,
+    scalar: &'static str,
+    base_size: usize,
+    product: &'static str,
+    add: &'static str,
+    reverse: &'static str,
+    inverse: &'static str,
+    exp: &'static str,
+}
+
+macro_rules! generate_ffi {
+    ($((
+        $name:ident,
+        $multi_vector:ident,
+        $scalar:ty,
+        $c_scalar:literal,
+        $product:ident,
+        $add:ident,
+        $reverse:ident,
+        $inverse:ident,
+        $exp:ident
+    )),+ $(,)?) => {
+        $(
+            #[doc = concat!("[", stringify!($multi_vector), "] of [", stringify!($scalar), "] with C layout.")]
+            #[repr(C)]
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            pub struct $name {
+                pub coefficients: [$scalar; <$multi_vector<$scalar> as RingMultiVector<$scalar>>::BASE_SIZE],
+            }
+
+            impl From<$multi_vector<$scalar>> for $name {
+                fn from(multi_vector: $multi_vector<$scalar>) -> Self {
+                    Self {
+                        coefficients: multi_vector.0,
+                    }
+                }
+            }
+
+            impl From<$name> for $multi_vector<$scalar> {
+                fn from(multi_vector: $name) -> Self {
+                    Self(multi_vector.coefficients)
+                }
+            }
+
+            /// Geometric product `a * b`.
+            #[no_mangle]
+            pub extern "C" fn $product(a: $name, b: $name) -> $name {
+                ($multi_vector::from(a) * $multi_vector::from(b)).into()
+            }
+
+            /// Per coefficient `a + b`.
+            #[no_mangle]
+            pub extern "C" fn $add(a: $name, b: $name) -> $name {
+                ($multi_vector::from(a) + $multi_vector::from(b)).into()
+            }
+
+            /// See [RingMultiVector::reverse].
+            #[no_mangle]
+            pub extern "C" fn $reverse(a: $name) -> $name {
+                $multi_vector::from(a).reverse().into()
+            }
+
+            /// See [MultiVector::inverse]. Writes the inverse to `out` and returns `true`, if
+            /// there is one, otherwise `out` is left untouched and `false` is returned.
+            ///
+            /// # Safety
+            ///
+            /// `out` has to be valid for writes.
+            #[no_mangle]
+            pub unsafe extern "C" fn $inverse(a: $name, out: *mut $name) -> bool {
+                match $multi_vector::from(a).inverse() {
+                    Some(inverse) => {
+                        out.write(inverse.into());
+                        true
+                    }
+                    None => false,
+                }
+            }
+
+            /// See [MultiVector::exp].
+            #[no_mangle]
+            pub extern "C" fn $exp(a: $name) -> $name {
+                $multi_vector::from(a).exp().into()
+            }
+        )+
+
+        const TYPES: &[FfiType] = &[$(
+            FfiType {
+                name: stringify!($name),
+                scalar: $c_scalar,
+                base_size: <$multi_vector<$scalar> as RingMultiVector<$scalar>>::BASE_SIZE,
+                product: stringify!($product),
+                add: stringify!($add),
+                reverse: stringify!($reverse),
+                inverse: stringify!($inverse),
+                exp: stringify!($exp),
+            },
+        )+];
+    };
+}
+
+generate_ffi!(
+    (
+        MultiVector2F32,
+        MultiVector2,
+        f32,
+        "float",
+        algebraic_multi_vector2_f32_product,
+        algebraic_multi_vector2_f32_add,
+        algebraic_multi_vector2_f32_reverse,
+        algebraic_multi_vector2_f32_inverse,
+        algebraic_multi_vector2_f32_exp
+    ),
+    (
+        MultiVector2F64,
+        MultiVector2,
+        f64,
+        "double",
+        algebraic_multi_vector2_f64_product,
+        algebraic_multi_vector2_f64_add,
+        algebraic_multi_vector2_f64_reverse,
+        algebraic_multi_vector2_f64_inverse,
+        algebraic_multi_vector2_f64_exp
+    ),
+    (
+        MultiVector3F32,
+        MultiVector3,
+        f32,
+        "float",
+        algebraic_multi_vector3_f32_product,
+        algebraic_multi_vector3_f32_add,
+        algebraic_multi_vector3_f32_reverse,
+        algebraic_multi_vector3_f32_inverse,
+        algebraic_multi_vector3_f32_exp
+    ),
+    (
+        MultiVector3F64,
+        MultiVector3,
+        f64,
+        "double",
+        algebraic_multi_vector3_f64_product,
+        algebraic_multi_vector3_f64_add,
+        algebraic_multi_vector3_f64_reverse,
+        algebraic_multi_vector3_f64_inverse,
+        algebraic_multi_vector3_f64_exp
+    ),
+    (
+        MultiVector4F32,
+        MultiVector4,
+        f32,
+        "float",
+        algebraic_multi_vector4_f32_product,
+        algebraic_multi_vector4_f32_add,
+        algebraic_multi_vector4_f32_reverse,
+        algebraic_multi_vector4_f32_inverse,
+        algebraic_multi_vector4_f32_exp
+    ),
+    (
+        MultiVector4F64,
+        MultiVector4,
+        f64,
+        "double",
+        algebraic_multi_vector4_f64_product,
+        algebraic_multi_vector4_f64_add,
+        algebraic_multi_vector4_f64_reverse,
+        algebraic_multi_vector4_f64_inverse,
+        algebraic_multi_vector4_f64_exp
+    ),
+    (
+        MultiVector5F32,
+        MultiVector5,
+        f32,
+        "float",
+        algebraic_multi_vector5_f32_product,
+        algebraic_multi_vector5_f32_add,
+        algebraic_multi_vector5_f32_reverse,
+        algebraic_multi_vector5_f32_inverse,
+        algebraic_multi_vector5_f32_exp
+    ),
+    (
+        MultiVector5F64,
+        MultiVector5,
+        f64,
+        "double",
+        algebraic_multi_vector5_f64_product,
+        algebraic_multi_vector5_f64_add,
+        algebraic_multi_vector5_f64_reverse,
+        algebraic_multi_vector5_f64_inverse,
+        algebraic_multi_vector5_f64_exp
+    ),
+);
+
+/// Generates the C header declaring the structs and functions of this module.
+pub fn header() -> String {
+    let declarations = TYPES
+        .iter()
+        .map(|t| {
+            let FfiType {
+                name,
+                scalar,
+                base_size,
+                product,
+                add,
+                reverse,
+                inverse,
+                exp,
+            } = t;
+            format!(
+                "typedef struct {name} {{
+    {scalar} coefficients[{base_size}];
+}} {name};
+
+{name} {product}({name} a, {name} b);
+{name} {add}({name} a, {name} b);
+{name} {reverse}({name} a);
+bool {inverse}({name} a, {name} *out);
+{name} {exp}({name} a);
+"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "/* Generated by algebraic::ffi::header(), do not edit. */
+#ifndef ALGEBRAIC_H
+#define ALGEBRAIC_H
+
+#include <stdbool.h>
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+/*
+ * The i-th coefficient belongs to the base element which is the product of the basis vectors
+ * corresponding to the set bits of i, e.g. coefficients[5] belongs to e1 e3.
+ * All basis vectors square to -1.
+ */
+
+{declarations}
+#ifdef __cplusplus
+}}
+#endif
+
+#endif /* ALGEBRAIC_H */
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::{align_of, size_of};
+
+    #[test]
+    fn header_up_to_date() {
+        assert_eq!(header(), include_str!("../include/algebraic.h"));
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<MultiVector2F32>(), 4 * size_of::<f32>());
+        assert_eq!(size_of::<MultiVector3F64>(), 8 * size_of::<f64>());
+        assert_eq!(size_of::<MultiVector5F64>(), 32 * size_of::<f64>());
+        assert_eq!(align_of::<MultiVector4F32>(), align_of::<f32>());
+        assert_eq!(size_of::<MultiVector4F64>(), size_of::<MultiVector4<f64>>());
+    }
+
+    #[test]
+    fn functions() {
+        let a: MultiVector3F64 = MultiVector3([1., 2., 3., 4., 5., 6., 7., 8.]).into();
+        let b: MultiVector3F64 = MultiVector3([8., 7., 6., 5., 4., 3., 2., 1.]).into();
+
+        assert_eq!(
+            algebraic_multi_vector3_f64_product(a, b).coefficients,
+            [-88.0, -18.0, 60.0, -18.0, 72.0, 102.0, -36.0, 114.0]
+        );
+        assert_eq!(algebraic_multi_vector3_f64_add(a, b).coefficients, [9.0; 8]);
+        assert_eq!(
+            algebraic_multi_vector3_f64_reverse(a).coefficients,
+            [1., 2., 3., -4., 5., -6., -7., -8.]
+        );
+
+        let mut inverse = a;
+        assert!(unsafe { algebraic_multi_vector3_f64_inverse(a, &mut inverse) });
+        let one = algebraic_multi_vector3_f64_product(a, inverse);
+        assert!((one.coefficients[0] - 1.0).abs() < 1e-10);
+        assert!(one.coefficients[1..].iter().all(|c| c.abs() < 1e-10));
+
+        let zero = MultiVector2F32 {
+            coefficients: [0.0; 4],
+        };
+        let mut out = zero;
+        assert!(!unsafe { algebraic_multi_vector2_f32_inverse(zero, &mut out) });
+        assert_eq!(
+            algebraic_multi_vector2_f32_exp(zero).coefficients,
+            [1.0, 0.0, 0.0, 0.0]
+        );
+    }
+}
@@ -0,0 +1,525 @@
+//! Conversions to and from vectors, complex numbers, quaternions, and dual quaternions of other
+//! representations.
+//!
+//! Vectors `(x, y, z, w)` are the grade-1 parts `x e1 + y e2 + z e3 + w e4`, i.e. the
+//! coefficients at the indices `1`, `2`, `4`, and `8`, as far as the dimension goes. Converting a
+//! multi vector to a vector drops all other grades. In 3D, bivectors are also
+//! [axial vectors](axial_vector), e.g. `a ∧ b` for the cross product `a × b`.
+//!
+//! The even part of [MultiVector2] is isomorphic to ℂ, that of [MultiVector3] to ℍ. As all basis
+//! vectors square to `-1`, the bivectors do, too, and multiply like the imaginary units:
+//!
+//! | complex / quaternion | multi vector |
+//! |----------------------|--------------|
+//! | `i` (ℂ)              | `e12` (index `3`) |
+//! | `i` (ℍ)              | `e23` (index `6`) |
+//! | `j` (ℍ)              | `-e13` (index `5`), i.e. `e31` |
+//! | `k` (ℍ)              | `e12` (index `3`) |
+//!
+//! so that `ij = k`, and the quaternion `cos(θ/2) + sin(θ/2) k` is the rotor rotating `e1` towards
+//! `e2` by `θ`. Converting a multi vector drops its odd part.
+//!
+//! [Motor]s of 3D PGA are dual quaternions `real + ε dual`. There, the Euclidean basis vectors
+//! square to `1`, so the imaginary units are `i = -e23`, `j = -e31`, `k = -e12`, and `ε` is the
+//! pseudoscalar `e1234`, i.e. `εi = e14`, `εj = e24`, and `εk = e34`.
+//!
+//! Quaternions are tuples `(w, x, y, z)` for `w + xi + yj + zk`, complex numbers are tuples
+//! `(re, im)`. With the `num-complex`, `glam`, `nalgebra`, and `mint` features, the conversions
+//! also exist for
+//! * [Complex](https://docs.rs/num-complex/latest/num_complex/struct.Complex.html),
+//! * [glam](https://docs.rs/glam/latest/glam)'s `Vec2` to `Vec4`, `DVec2` to `DVec4`, `Quat`,
+//!   and `DQuat`,
+//! * [nalgebra](https://docs.rs/nalgebra/latest/nalgebra)'s `Vector2` to `Vector4`, and
+//!   `UnitQuaternion`,
+//! * [mint](https://docs.rs/mint/latest/mint)'s `Vector2` to `Vector4`, and `Quaternion`, as a
+//!   neutral interchange with other libraries.
+//!
+//! ```
+//! # use algebraic::MultiVector3;
+//! let i = MultiVector3::from((0, 1, 0, 0));
+//! let j = MultiVector3::from((0, 0, 1, 0));
+//! assert_eq!(<(_, _, _, _)>::from(i * j), (0, 0, 0, 1));
+//! ```
+use crate::{Motor, MultiVector2, MultiVector3, Ring};
+
+// indices of the basis vectors, i.e. of `x`, `y`, `z`, and `w`
+#[cfg(any(feature = "glam", feature = "mint", feature = "nalgebra", test))]
+const VECTOR: [usize; 4] = [0b0001, 0b0010, 0b0100, 0b1000];
+// indices of the bivectors
+const E12: usize = 0b011;
+const E13: usize = 0b101;
+const E23: usize = 0b110;
+
+// the grade-1 part, see the module documentation
+#[cfg(any(feature = "glam", feature = "mint", feature = "nalgebra", test))]
+fn from_vector<T: Ring, M: crate::RingMultiVector<T>>(
+    coordinates: impl IntoIterator<Item = T>,
+) -> M {
+    let mut a = M::default();
+    for (i, x) in VECTOR.into_iter().zip(coordinates) {
+        a[i] = x;
+    }
+    a
+}
+
+// the coefficients of the grade-1 part
+#[cfg(any(feature = "glam", feature = "mint", feature = "nalgebra", test))]
+fn vector<T: Ring, M: crate::RingMultiVector<T>, const N: usize>(a: M) -> [T; N] {
+    std::array::from_fn(|i| a[VECTOR[i]])
+}
+
+/// The axial vector `(x, y, z)` of the bivector part of `a`, `x e23 - y e13 + z e12`, i.e. the
+/// coefficients at the indices `6`, `5` (negated), and `3`. E.g. the cross product of the outer
+/// product.
+pub fn axial_vector<T: Ring>(a: MultiVector3<T>) -> [T; 3] {
+    [a[E23], -a[E13], a[E12]]
+}
+
+/// The bivector `x e23 - y e13 + z e12` of the axial vector `(x, y, z)`, see [axial_vector].
+pub fn bivector<T: Ring>([x, y, z]: [T; 3]) -> MultiVector3<T> {
+    let mut a = MultiVector3::default();
+    a[E23] = x;
+    a[E13] = -y;
+    a[E12] = z;
+    a
+}
+
+/// `re + im e12`.
+impl<T: Ring> From<(T, T)> for MultiVector2<T> {
+    fn from((re, im): (T, T)) -> Self {
+        let mut a = Self::default();
+        a[0] = re;
+        a[E12] = im;
+        a
+    }
+}
+
+/// `(re, im)` of the even part.
+impl<T: Ring> From<MultiVector2<T>> for (T, T) {
+    fn from(a: MultiVector2<T>) -> Self {
+        (a[0], a[E12])
+    }
+}
+
+/// `w + x e23 - y e13 + z e12`.
+impl<T: Ring> From<(T, T, T, T)> for MultiVector3<T> {
+    fn from((w, x, y, z): (T, T, T, T)) -> Self {
+        let mut a = bivector([x, y, z]);
+        a[0] = w;
+        a
+    }
+}
+
+/// `(w, x, y, z)` of the even part.
+impl<T: Ring> From<MultiVector3<T>> for (T, T, T, T) {
+    fn from(a: MultiVector3<T>) -> Self {
+        let [x, y, z] = axial_vector(a);
+        (a[0], x, y, z)
+    }
+}
+
+/// The dual quaternion `real + ε dual`, as `(real, dual)`.
+impl<T: Ring> From<((T, T, T, T), (T, T, T, T))> for Motor<T> {
+    fn from(((w, x, y, z), (dw, dx, dy, dz)): ((T, T, T, T), (T, T, T, T))) -> Self {
+        // 1, e12, e13, e23, e14, e24, e34, e1234
+        Motor([w, -z, y, -x, dx, dy, dz, dw])
+    }
+}
+
+/// `(real, dual)` of the dual quaternion `real + ε dual`.
+impl<T: Ring> From<Motor<T>> for ((T, T, T, T), (T, T, T, T)) {
+    fn from(Motor([w, e12, e13, e23, e14, e24, e34, e1234]): Motor<T>) -> Self {
+        ((w, -e23, e13, -e12), (e1234, e14, e24, e34))
+    }
+}
+
+#[cfg(feature = "num-complex")]
+mod num_complex_conversions {
+    use super::*;
+    use num_complex::Complex;
+
+    /// `re + im e12`.
+    impl<T: Ring> From<Complex<T>> for MultiVector2<T> {
+        fn from(z: Complex<T>) -> Self {
+            Self::from((z.re, z.im))
+        }
+    }
+
+    /// The even part.
+    impl<T: Ring> From<MultiVector2<T>> for Complex<T> {
+        fn from(a: MultiVector2<T>) -> Self {
+            let (re, im) = a.into();
+            Complex::new(re, im)
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_conversions {
+    use super::*;
+    use crate::MultiVector4;
+    use glam::{DQuat, DVec2, DVec3, DVec4, Quat, Vec2, Vec3, Vec4};
+
+    macro_rules! impl_vector {
+        ($($vector:ident: $name:ident<$float:ty>, $doc:literal),*) => {
+            $(
+                #[doc = $doc]
+                impl From<$vector> for $name<$float> {
+                    fn from(v: $vector) -> Self {
+                        from_vector(v.to_array())
+                    }
+                }
+
+                /// The grade-1 part, see the other direction.
+                impl From<$name<$float>> for $vector {
+                    fn from(a: $name<$float>) -> Self {
+                        <$vector>::from_array(vector(a))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vector!(
+        Vec2: MultiVector2<f32>, "`x e1 + y e2`, i.e. at the indices `1` and `2`.",
+        Vec3: MultiVector3<f32>, "`x e1 + y e2 + z e3`, i.e. at the indices `1`, `2`, and `4`.",
+        Vec4: MultiVector4<f32>,
+        "`x e1 + y e2 + z e3 + w e4`, i.e. at the indices `1`, `2`, `4`, and `8`.",
+        DVec2: MultiVector2<f64>, "`x e1 + y e2`, i.e. at the indices `1` and `2`.",
+        DVec3: MultiVector3<f64>, "`x e1 + y e2 + z e3`, i.e. at the indices `1`, `2`, and `4`.",
+        DVec4: MultiVector4<f64>,
+        "`x e1 + y e2 + z e3 + w e4`, i.e. at the indices `1`, `2`, `4`, and `8`."
+    );
+
+    macro_rules! impl_quat {
+        ($($quat:ty: $float:ty),*) => {
+            $(
+                /// `w + x e23 - y e13 + z e12`.
+                impl From<$quat> for MultiVector3<$float> {
+                    fn from(q: $quat) -> Self {
+                        Self::from((q.w, q.x, q.y, q.z))
+                    }
+                }
+
+                /// The even part, not normalized.
+                impl From<MultiVector3<$float>> for $quat {
+                    fn from(a: MultiVector3<$float>) -> Self {
+                        let (w, x, y, z) = a.into();
+                        <$quat>::from_xyzw(x, y, z, w)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_quat!(Quat: f32, DQuat: f64);
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conversions {
+    use super::*;
+    use crate::MultiVector4;
+    use nalgebra::{Quaternion, RealField, UnitQuaternion, Vector2, Vector3, Vector4};
+
+    macro_rules! impl_vector {
+        ($($vector:ident: $name:ident, $doc:literal),*) => {
+            $(
+                #[doc = $doc]
+                impl<T: nalgebra::Scalar + Ring> From<$vector<T>> for $name<T> {
+                    fn from(v: $vector<T>) -> Self {
+                        from_vector(v.iter().copied())
+                    }
+                }
+
+                /// The grade-1 part, see the other direction.
+                impl<T: nalgebra::Scalar + Ring> From<$name<T>> for $vector<T> {
+                    fn from(a: $name<T>) -> Self {
+                        <$vector<T>>::from(vector(a))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vector!(
+        Vector2: MultiVector2, "`x e1 + y e2`, i.e. at the indices `1` and `2`.",
+        Vector3: MultiVector3, "`x e1 + y e2 + z e3`, i.e. at the indices `1`, `2`, and `4`.",
+        Vector4: MultiVector4,
+        "`x e1 + y e2 + z e3 + w e4`, i.e. at the indices `1`, `2`, `4`, and `8`."
+    );
+
+    /// `w + x e23 - y e13 + z e12`.
+    impl<T: RealField + Ring> From<UnitQuaternion<T>> for MultiVector3<T> {
+        fn from(q: UnitQuaternion<T>) -> Self {
+            Self::from((q.w, q.i, q.j, q.k))
+        }
+    }
+
+    /// The normalized even part.
+    impl<T: RealField + Ring> From<MultiVector3<T>> for UnitQuaternion<T> {
+        fn from(a: MultiVector3<T>) -> Self {
+            let (w, x, y, z) = a.into();
+            UnitQuaternion::from_quaternion(Quaternion::new(w, x, y, z))
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint_conversions {
+    use super::*;
+    use crate::MultiVector4;
+    use mint::{Quaternion, Vector2, Vector3, Vector4};
+
+    /// `x e1 + y e2`, i.e. at the indices `1` and `2`.
+    impl<T: Ring> From<Vector2<T>> for MultiVector2<T> {
+        fn from(v: Vector2<T>) -> Self {
+            from_vector([v.x, v.y])
+        }
+    }
+
+    /// The grade-1 part, see the other direction.
+    impl<T: Ring> From<MultiVector2<T>> for Vector2<T> {
+        fn from(a: MultiVector2<T>) -> Self {
+            let [x, y] = vector(a);
+            Vector2 { x, y }
+        }
+    }
+
+    /// `x e1 + y e2 + z e3`, i.e. at the indices `1`, `2`, and `4`.
+    impl<T: Ring> From<Vector3<T>> for MultiVector3<T> {
+        fn from(v: Vector3<T>) -> Self {
+            from_vector([v.x, v.y, v.z])
+        }
+    }
+
+    /// The grade-1 part, see the other direction.
+    impl<T: Ring> From<MultiVector3<T>> for Vector3<T> {
+        fn from(a: MultiVector3<T>) -> Self {
+            let [x, y, z] = vector(a);
+            Vector3 { x, y, z }
+        }
+    }
+
+    /// `x e1 + y e2 + z e3 + w e4`, i.e. at the indices `1`, `2`, `4`, and `8`.
+    impl<T: Ring> From<Vector4<T>> for MultiVector4<T> {
+        fn from(v: Vector4<T>) -> Self {
+            from_vector([v.x, v.y, v.z, v.w])
+        }
+    }
+
+    /// The grade-1 part, see the other direction.
+    impl<T: Ring> From<MultiVector4<T>> for Vector4<T> {
+        fn from(a: MultiVector4<T>) -> Self {
+            let [x, y, z, w] = vector(a);
+            Vector4 { x, y, z, w }
+        }
+    }
+
+    /// `s + v.x e23 - v.y e13 + v.z e12`.
+    impl<T: Ring> From<Quaternion<T>> for MultiVector3<T> {
+        fn from(q: Quaternion<T>) -> Self {
+            Self::from((q.s, q.v.x, q.v.y, q.v.z))
+        }
+    }
+
+    /// The even part.
+    impl<T: Ring> From<MultiVector3<T>> for Quaternion<T> {
+        fn from(a: MultiVector3<T>) -> Self {
+            let (s, x, y, z) = a.into();
+            Quaternion {
+                v: Vector3 { x, y, z },
+                s,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{multi_vector::outer_product, PgaMultiVector, Rotor};
+
+    type Quaternion = (i64, i64, i64, i64);
+
+    fn hamilton((a, b, c, d): Quaternion, (e, f, g, h): Quaternion) -> Quaternion {
+        (
+            a * e - b * f - c * g - d * h,
+            a * f + b * e + c * h - d * g,
+            a * g - b * h + c * e + d * f,
+            a * h + b * g - c * f + d * e,
+        )
+    }
+
+    #[test]
+    fn complex() {
+        let (a, b) = (MultiVector2::from((3, -2)), MultiVector2::from((1, 4)));
+        // (3 - 2i)(1 + 4i) = 11 + 10i
+        assert_eq!(<(_, _)>::from(a * b), (11, 10));
+        assert_eq!(<(_, _)>::from(MultiVector2([1, 2, 3, 4])), (1, 4));
+    }
+
+    #[test]
+    fn quaternions() {
+        let units = [(1, 0, 0, 0), (0, 1, 0, 0), (0, 0, 1, 0), (0, 0, 0, 1)];
+        let samples = [(1, -2, 3, 5), (-4, 0, 7, 1), (2, 3, -1, -6)];
+        for p in units.into_iter().chain(samples) {
+            for q in units.into_iter().chain(samples) {
+                let product = MultiVector3::from(p) * MultiVector3::from(q);
+                assert_eq!(<(_, _, _, _)>::from(product), hamilton(p, q));
+            }
+        }
+
+        // the quaternion cos(θ/2) + sin(θ/2) k rotates around z, like the rotor
+        let angle = 0.8f64;
+        let q = MultiVector3::from(((angle / 2.0).cos(), 0.0, 0.0, (angle / 2.0).sin()));
+        let matrix = q.to_rotation_matrix();
+        assert!((matrix[1][0] - angle.sin()).abs() < 1e-12);
+        assert!((matrix[0][1] + angle.sin()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn dual_quaternions() {
+        let dual = |(a, b): (Quaternion, Quaternion), (c, d): (Quaternion, Quaternion)| {
+            let (e, f) = (hamilton(a, d), hamilton(b, c));
+            (hamilton(a, c), (e.0 + f.0, e.1 + f.1, e.2 + f.2, e.3 + f.3))
+        };
+        let samples = [
+            ((1, 0, 0, 0), (0, 1, 0, 0)),
+            ((0, 1, 0, 0), (0, 0, 0, 1)),
+            ((1, -2, 3, 5), (2, 0, -1, 4)),
+            ((-4, 0, 7, 1), (3, 3, 1, -2)),
+        ];
+        for p in samples {
+            for q in samples {
+                let product = Motor::from(p) * Motor::from(q);
+                assert_eq!(<(_, _)>::from(product), dual(p, q));
+            }
+        }
+
+        // the translation t is `1 + ε t/2`, the rotation is the usual quaternion
+        let translator = Motor::from(PgaMultiVector::translator(2.0, -4.0, 6.0));
+        assert_eq!(
+            <(_, _)>::from(translator),
+            ((1.0, 0.0, 0.0, 0.0), (0.0, 1.0, -2.0, 3.0))
+        );
+        let rotor = Motor::from(PgaMultiVector::rotor([0.0, 0.0, 1.0], 0.8));
+        let ((w, x, y, z), _) = rotor.into();
+        assert!((w - 0.4f64.cos()).abs() < 1e-12 && (z - 0.4f64.sin()).abs() < 1e-12);
+        assert_eq!((x, y), (0.0, 0.0));
+    }
+
+    #[test]
+    fn axial_vectors() {
+        let (a, b) = ([1, -2, 3], [4, 0, -5]);
+        // (-2 * -5 - 3 * 0, 3 * 4 - 1 * -5, 1 * 0 - -2 * 4)
+        let cross = [10, 17, 8];
+        let outer = outer_product(from_vector::<_, MultiVector3<_>>(a), from_vector(b));
+        assert_eq!(axial_vector(outer), cross);
+        assert_eq!(bivector(cross), outer);
+        assert_eq!(vector::<_, _, 3>(from_vector::<_, MultiVector3<_>>(a)), a);
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn num_complex() {
+        use num_complex::Complex;
+
+        let z = Complex::new(0.5, -1.5);
+        assert_eq!(Complex::from(MultiVector2::from(z)), z);
+        assert_eq!(
+            MultiVector2::from(z * z),
+            MultiVector2::from(z) * MultiVector2::from(z)
+        );
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam() {
+        use crate::MultiVector4;
+        use glam::{DQuat, DVec3, DVec4, Quat, Vec3};
+
+        let q = DQuat::from_axis_angle(DVec3::new(1.0, 2.0, 2.0) / 3.0, 0.7);
+        let rotor = MultiVector3::from(q);
+        assert_eq!(DQuat::from(rotor), q);
+        let v = DVec3::new(1.0, -1.0, 3.0);
+        let matrix = rotor.to_rotation_matrix();
+        let rotated = q * v;
+        for (row, expected) in matrix.iter().zip(rotated.to_array()) {
+            let product: f64 = row.iter().zip(v.to_array()).map(|(a, b)| a * b).sum();
+            assert!((product - expected).abs() < 1e-12);
+        }
+
+        let u = Vec3::new(1.0, 2.0, -0.5);
+        assert_eq!(Vec3::from(MultiVector3::from(u)), u);
+        assert_eq!(MultiVector3::from(u)[4], -0.5);
+        let w = DVec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(MultiVector4::from(w)[8], 4.0);
+        assert_eq!(DVec4::from(MultiVector4::from(w)), w);
+        // the cross product
+        let (a, b) = (DVec3::new(1.0, -2.0, 3.0), DVec3::new(4.0, 0.5, -5.0));
+        let outer = outer_product(MultiVector3::from(a), MultiVector3::from(b));
+        assert_eq!(DVec3::from(axial_vector(outer)), a.cross(b));
+
+        let (p, q) = (Quat::from_rotation_x(0.3), Quat::from_rotation_y(-1.1));
+        let product = MultiVector3::from(p) * MultiVector3::from(q);
+        assert!(Quat::from(product).abs_diff_eq(p * q, 1e-6));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra() {
+        use crate::MultiVector4;
+        use nalgebra::{UnitQuaternion, Vector2, Vector3, Vector4};
+
+        let u = Vector2::new(3, -4);
+        assert_eq!(MultiVector2::from(u), MultiVector2([0, 3, -4, 0]));
+        assert_eq!(Vector2::from(MultiVector2([1, 3, -4, 2])), u);
+        let w = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(MultiVector4::from(w)[4], 3.0);
+        assert_eq!(Vector4::from(MultiVector4::from(w)), w);
+        let (a, b) = (Vector3::new(1.0, -2.0, 3.0), Vector3::new(4.0, 0.5, -5.0));
+        let outer = outer_product(MultiVector3::from(a), MultiVector3::from(b));
+        assert_eq!(Vector3::from(axial_vector(outer)), a.cross(&b));
+
+        let q = UnitQuaternion::<f64>::from_euler_angles(0.3, -0.2, 1.1);
+        let rotor = MultiVector3::from(q);
+        assert!((UnitQuaternion::from(rotor).angle_to(&q)).abs() < 1e-12);
+        let v = Vector3::new(1.0, -1.0, 3.0);
+        let rotated = q * v;
+        let matrix = rotor.to_rotation_matrix();
+        for (row, expected) in matrix.iter().zip(rotated.iter()) {
+            let product: f64 = row.iter().zip(v.iter()).map(|(a, b)| a * b).sum();
+            assert!((product - expected).abs() < 1e-12);
+        }
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint() {
+        use crate::MultiVector4;
+
+        let v = mint::Vector3 { x: 1, y: 2, z: 3 };
+        assert_eq!(
+            MultiVector3::from(v),
+            MultiVector3([0, 1, 2, 0, 3, 0, 0, 0])
+        );
+        assert_eq!(mint::Vector3::from(MultiVector3::from(v)), v);
+        let v = mint::Vector4 {
+            x: 1,
+            y: 2,
+            z: 3,
+            w: 4,
+        };
+        assert_eq!(mint::Vector4::from(MultiVector4::from(v)), v);
+        assert_eq!(MultiVector4::from(v)[8], 4);
+        let v = mint::Vector2 { x: 5, y: 6 };
+        assert_eq!(mint::Vector2::from(MultiVector2::from(v)), v);
+
+        let q = mint::Quaternion {
+            v: mint::Vector3 { x: 1, y: 2, z: 3 },
+            s: 4,
+        };
+        assert_eq!(<(_, _, _, _)>::from(MultiVector3::from(q)), (4, 1, 2, 3));
+        assert_eq!(mint::Quaternion::from(MultiVector3::from(q)), q);
+    }
+}
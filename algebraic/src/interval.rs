@@ -0,0 +1,266 @@
+//! This module contains [Interval], an [f64] enclosure for verified computations.
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A closed interval `[lo, hi]` of [f64]s with outward rounding, a [Ring](crate::Ring).
+///
+/// Every operation rounds its lower bound down and its upper bound up, so the result contains
+/// the exact result for all values in the operands. E.g. the geometric product of multi vectors
+/// of intervals contains the exact product of any multi vectors within them. Bounds may be
+/// infinite, e.g. after a division by an interval containing zero, and multiply like in standard
+/// interval arithmetic, i.e. `0 * inf = 0`.
+///
+/// ```
+/// # use algebraic::{Interval, MultiVector2};
+/// use std::cmp::Ordering;
+///
+/// let third = Interval::point(1.0) / Interval::point(3.0);
+/// let a = MultiVector2([third, third, Interval::point(0.0), third]);
+/// // the scalar part is 1/9 - 1/9 - 1/9
+/// assert_eq!((a * a)[0].sign(), Some(Ordering::Less));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+impl Interval {
+    /// The interval `[lo, hi]`, the bounds may be infinite.
+    ///
+    /// # Panics
+    ///
+    /// Unless `lo <= hi`, in particular if either is NaN.
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "invalid interval [{lo}, {hi}]");
+        Self { lo, hi }
+    }
+
+    /// The degenerate interval `[value, value]`.
+    ///
+    /// # Panics
+    ///
+    /// If `value` is NaN.
+    pub fn point(value: f64) -> Self {
+        Self::new(value, value)
+    }
+
+    /// Everything, e.g. the result of dividing by an interval containing zero.
+    pub fn entire() -> Self {
+        Self::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    pub fn width(self) -> f64 {
+        self.hi - self.lo
+    }
+
+    pub fn contains(self, value: f64) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// The sign of all values in the interval, `None` if it contains zero and other values.
+    pub fn sign(self) -> Option<Ordering> {
+        if self.lo > 0.0 {
+            Some(Ordering::Greater)
+        } else if self.hi < 0.0 {
+            Some(Ordering::Less)
+        } else if self.lo == 0.0 && self.hi == 0.0 {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+
+    /// # Panics
+    ///
+    /// If the interval contains negative values.
+    pub fn sqrt(self) -> Self {
+        assert!(self.lo >= 0.0, "square root of {self}");
+        Self::new(
+            self.lo.sqrt().next_down().max(0.0),
+            self.hi.sqrt().next_up(),
+        )
+    }
+
+    // rounds outward, `lo` and `hi` are rounded to nearest, NaN (e.g. from `inf - inf`) could be
+    // anything
+    fn outward(lo: f64, hi: f64) -> Self {
+        Self::new(
+            if lo.is_nan() {
+                f64::NEG_INFINITY
+            } else {
+                lo.next_down()
+            },
+            if hi.is_nan() {
+                f64::INFINITY
+            } else {
+                hi.next_up()
+            },
+        )
+    }
+
+    // the hull of the candidates, rounded outward
+    fn hull(candidates: [f64; 4]) -> Self {
+        if candidates.iter().any(|x| x.is_nan()) {
+            return Self::entire();
+        }
+        Self::outward(
+            candidates.into_iter().fold(f64::INFINITY, f64::min),
+            candidates.into_iter().fold(f64::NEG_INFINITY, f64::max),
+        )
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // `0 * inf` is `0`, the bounds only stand for the finite values close to them
+        let mul = |a: f64, b: f64| if a == 0.0 || b == 0.0 { 0.0 } else { a * b };
+        Self::hull([
+            mul(self.lo, rhs.lo),
+            mul(self.lo, rhs.hi),
+            mul(self.hi, rhs.lo),
+            mul(self.hi, rhs.hi),
+        ])
+    }
+}
+
+/// The [entire](Interval::entire) line if `rhs` contains zero.
+impl Div for Interval {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains(0.0) {
+            return Self::entire();
+        }
+        Self::hull([
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        ])
+    }
+}
+
+/// Exact.
+impl Neg for Interval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.hi, -self.lo)
+    }
+}
+
+/// E.g. `[0.33, 0.34]`, precision applies to both bounds.
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "[{:.precision$}, {:.precision$}]", self.lo, self.hi),
+            None => write!(f, "[{}, {}]", self.lo, self.hi),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MultiVector2;
+
+    #[test]
+    fn arithmetic() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(-3.0, 0.5);
+        assert!((a + b).contains(-2.0) && (a + b).contains(2.5));
+        assert!((a - b).contains(0.5) && (a - b).contains(5.0));
+        assert!((a * b).contains(-6.0) && (a * b).contains(1.0));
+        assert_eq!(-a, Interval::new(-2.0, -1.0));
+        assert_eq!(a / b, Interval::entire());
+
+        let c = Interval::point(1.0) / Interval::point(10.0);
+        assert!(c.lo() < c.hi() && c.contains(0.1));
+        assert!((c * Interval::point(10.0)).contains(1.0));
+        assert!((c + c + c).width() < 1e-15);
+        assert!(Interval::point(2.0)
+            .sqrt()
+            .contains(std::f64::consts::SQRT_2));
+    }
+
+    #[test]
+    fn infinite() {
+        let entire = Interval::entire();
+        let zero = entire * Interval::point(0.0);
+        assert!(zero.contains(0.0) && zero.width() < 1e-300);
+        assert!((entire * Interval::new(0.0, 1.0)).contains(-1e300));
+        assert_eq!(entire + entire, entire);
+        assert_eq!(entire - entire, entire);
+        assert_eq!(entire / Interval::new(1.0, f64::INFINITY), entire);
+        let infinity = Interval::point(f64::INFINITY);
+        assert_eq!(infinity - infinity, entire);
+
+        // e.g. after dividing by zero, all products still multiply some coefficients by zero
+        let a = MultiVector2([
+            Interval::point(1.0) / Interval::point(0.0),
+            Interval::point(1.0),
+            Interval::point(0.0),
+            Interval::point(0.0),
+        ]);
+        let b = MultiVector2([
+            Interval::point(0.0),
+            Interval::point(0.0),
+            Interval::point(2.0),
+            Interval::point(0.0),
+        ]);
+        let product = a * b;
+        assert!(product[0].contains(0.0) && product[0].width() < 1e-300);
+        assert_eq!(product[2], entire);
+        assert!(product[3].contains(2.0));
+    }
+
+    #[test]
+    fn sign() {
+        assert_eq!(Interval::new(1e-300, 1.0).sign(), Some(Ordering::Greater));
+        assert_eq!(Interval::new(-1.0, -1e-300).sign(), Some(Ordering::Less));
+        assert_eq!(Interval::point(0.0).sign(), Some(Ordering::Equal));
+        assert_eq!(Interval::new(-1e-300, 1.0).sign(), None);
+        // 0.1 + 0.2 == 0.3 in floating point, but not exactly
+        let sum = Interval::point(0.1) + Interval::point(0.2) - Interval::point(0.3);
+        assert_eq!(sum.sign(), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{:.2}", Interval::new(0.333, 0.337)),
+            "[0.33, 0.34]"
+        );
+        assert_eq!(Interval::point(1.5).to_string(), "[1.5, 1.5]");
+    }
+}
@@ -3,23 +3,89 @@
 //!
 //! This crate provides the [MultiVector] trait with 7 implementations, i.e. for algebra dimensions
 //! from Zero to Six.
-//! This enables writing code that is generic in the algebra dimension.
+//! This enables writing code that is generic in the algebra dimension. Exact coefficients like
+//! integers or [ModP] are supported by the [RingMultiVector] part of it. With [Dual] coefficients,
+//! derivatives are propagated through all operations, see [dual]. [Interval] coefficients enclose
+//! the exact results despite rounding, and [Symbol] coefficients yield the products as formulas.
 //! The implementations provide algebraic structures that overload operators `+`, `-`, and `*`. The code for multiplying is generated using the
 //! [generate_geometric_product](https://docs.rs/algebraic-gen/latest/algebraic_gen/macro.generate_geometric_product.html) macro.
 //!
 //! In a certain sense, this crate only exists because proc-macro crates cannot export anything but
 //! proc-macros. [algebra-gen](https://docs.rs/crate/algebraic-gen/latest) doesn't really have any
-//! unit-testing to speak of, testing happens here.
+//! unit-testing to speak of, testing happens here. (The generation logic itself is available as a
+//! normal library in [algebraic-core](https://docs.rs/algebraic-core/latest/algebraic_core).)
+//!
+//! Rotors in 2, 3, and 4 dimensions are interpolated and blended with [Rotor]. For 3D rigid-body
+//! geometry, [PgaMultiVector] implements the projective geometric algebra `Cl(3,0,1)`, with
+//! points, planes, lines, and motors in [pga], and interpolation of [Motor]s. For spheres,
+//! circles, and point pairs, [CgaMultiVector] implements the conformal geometric algebra
+//! `Cl(4,1)`, see [cga]. Relativistic physics, e.g. boosts and electromagnetic fields, is covered
+//! by the spacetime algebra [StaMultiVector] of `Cl(1,3)` in [sta].
+//!
+//! Multi vectors are printed as sums of blades with [Display](std::fmt::Display), e.g.
+//! `1 + 2e1 - 0.5e12 + 3e123`, see [display]. Such strings are parsed back with
+//! [FromStr](std::str::FromStr), see [parse].
+//!
+//! # Features
+//!
+//! * `approx`: [AbsDiffEq](https://docs.rs/approx/latest/approx/trait.AbsDiffEq.html),
+//!   [RelativeEq](https://docs.rs/approx/latest/approx/trait.RelativeEq.html), and
+//!   [UlpsEq](https://docs.rs/approx/latest/approx/trait.UlpsEq.html) for the multi vectors.
+//! * `bytemuck`: [Pod](https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html) and
+//!   [Zeroable](https://docs.rs/bytemuck/latest/bytemuck/trait.Zeroable.html) for the multi
+//!   vectors, e.g. to cast `&[MultiVector3<f32>]` to `&[u8]` without copying.
+//! * `ffi`: The [ffi] module, a C-compatible layer with an accompanying header.
+//! * `glam`: Conversions between [glam](https://docs.rs/glam/latest/glam) vectors and
+//!   quaternions, and multi vectors, see [interop].
+//! * `mint`: The same for [mint](https://docs.rs/mint/latest/mint) types, see [interop].
+//! * `nalgebra`: Conversions between [nalgebra](https://docs.rs/nalgebra/latest/nalgebra)
+//!   vectors and quaternions, and multi vectors, see [interop].
+//! * `num-complex`: Multi vectors over the complex numbers, see [complex], and conversions to
+//!   and from the even part of [MultiVector2], see [interop].
+//! * `num-traits`: [Scalar] for every [num_traits::Float](https://docs.rs/num-traits/latest/num_traits/float/trait.Float.html)
+//!   through the `Float` wrapper, and [Zero](https://docs.rs/num-traits/latest/num_traits/identities/trait.Zero.html) and
+//!   [One](https://docs.rs/num-traits/latest/num_traits/identities/trait.One.html) for the multi
+//!   vectors.
+//! * `serde`: Serialization of the multi vectors, see [serialization].
 //!
 //! # Example & More
 //!
 //! TODO
 //!
+pub mod cga;
+pub mod display;
+pub mod dual;
+pub mod interop;
+pub mod interval;
+pub mod mod_p;
+pub mod motor;
 pub mod multi_vector;
+pub mod parse;
+pub mod pga;
+pub mod rotor;
+pub mod scalar;
+pub mod sta;
+pub mod symbol;
+pub use dual::Dual;
+pub use interval::Interval;
+pub use mod_p::ModP;
+pub use motor::Motor;
 pub use multi_vector::{
-    MultiVector, MultiVector0, MultiVector1, MultiVector2, MultiVector3, MultiVector4,
-    MultiVector5, MultiVector6,
+    CgaMultiVector, MultiVector, MultiVector0, MultiVector1, MultiVector2, MultiVector3,
+    MultiVector4, MultiVector5, MultiVector6, PgaMultiVector, RingMultiVector, StaMultiVector,
 };
+pub use rotor::Rotor;
+#[cfg(feature = "num-traits")]
+pub use scalar::Float;
+pub use scalar::{Ring, Scalar};
+pub use symbol::Symbol;
+
+#[cfg(feature = "num-complex")]
+pub mod complex;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "serde")]
+pub mod serialization;
 
 #[cfg(test)]
 mod unit_tests;
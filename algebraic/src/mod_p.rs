@@ -0,0 +1,182 @@
+//! This module contains [ModP], integers modulo `P`, for exact multi vectors over finite fields.
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    num::ParseIntError,
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// The integers modulo `P`, a [Ring](crate::Ring) and, for prime `P`, the finite field GF(P).
+///
+/// The value is always reduced, i.e. in `0..P`. `P` has to be at least `2`, which is
+/// checked at compile time by every constructor and operation.
+///
+/// ```
+/// # use algebraic::{ModP, MultiVector2};
+/// let a = MultiVector2([1, 2, 3, 4].map(ModP::<5>::new));
+/// assert_eq!(a * a, MultiVector2([2, 4, 1, 3].map(ModP::new)));
+/// ```
+///
+/// ```compile_fail
+/// # use algebraic::ModP;
+/// let a = ModP::<1>::new(3);
+/// ```
+///
+/// ```compile_fail
+/// # use algebraic::ModP;
+/// let a = ModP::<0>::default();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModP<const P: u64>(u64);
+
+impl<const P: u64> ModP<P> {
+    // `P`, referenced by every constructor and operation so that `P < 2` fails to compile
+    const MODULUS: u64 = {
+        assert!(P >= 2, "the modulus has to be at least 2");
+        P
+    };
+
+    /// Reduces the value modulo `P`, negative values are wrapped.
+    pub fn new(value: i64) -> Self {
+        Self((value as i128).rem_euclid(Self::MODULUS as i128) as u64)
+    }
+
+    /// The representative in `0..P`.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self(1 % Self::MODULUS);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// The multiplicative inverse, if there is one (always, except for zero, if `P` is prime).
+    pub fn inverse(self) -> Option<Self> {
+        // extended euclidean algorithm
+        let (mut r, mut next_r) = (Self::MODULUS as i128, self.0 as i128);
+        let (mut t, mut next_t) = (0i128, 1i128);
+        while next_r != 0 {
+            let quotient = r / next_r;
+            (r, next_r) = (next_r, r - quotient * next_r);
+            (t, next_t) = (next_t, t - quotient * next_t);
+        }
+        (r == 1).then(|| Self(t.rem_euclid(Self::MODULUS as i128) as u64))
+    }
+}
+
+impl<const P: u64> Default for ModP<P> {
+    fn default() -> Self {
+        Self(0 % Self::MODULUS)
+    }
+}
+
+impl<const P: u64> Add for ModP<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(((self.0 as u128 + rhs.0 as u128) % Self::MODULUS as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for ModP<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Mul for ModP<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self((self.0 as u128 * rhs.0 as u128 % Self::MODULUS as u128) as u64)
+    }
+}
+
+/// Panics if `rhs` has no inverse, see [ModP::inverse].
+impl<const P: u64> Div for ModP<P> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let inverse = rhs
+            .inverse()
+            .unwrap_or_else(|| panic!("{rhs} is not invertible modulo {P}"));
+        self.mul(inverse)
+    }
+}
+
+impl<const P: u64> Neg for ModP<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self((Self::MODULUS - self.0) % Self::MODULUS)
+    }
+}
+
+impl<const P: u64> Debug for ModP<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {P})", self.0)
+    }
+}
+
+impl<const P: u64> Display for ModP<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// Parses an integer and reduces it.
+impl<const P: u64> FromStr for ModP<P> {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse::<i128>()?;
+        Ok(Self(value.rem_euclid(Self::MODULUS as i128) as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type F7 = ModP<7>;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(F7::new(-1), F7::new(6));
+        assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+        assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+        assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+        assert_eq!(-F7::new(0), F7::new(0));
+        assert_eq!(F7::new(3).pow(6), F7::new(1));
+        assert_eq!(F7::new(1) / F7::new(3), F7::new(5));
+        assert_eq!("-8".parse(), Ok(F7::new(6)));
+        assert_eq!(format!("{:?}", F7::new(3)), "3 (mod 7)");
+
+        // no overflow close to u64::MAX
+        type Big = ModP<{ u64::MAX - 58 }>;
+        let a = Big::new(-1);
+        assert_eq!(a * a, Big::new(1));
+        assert_eq!(a + a, Big::new(-2));
+    }
+
+    #[test]
+    fn inverse() {
+        for a in 1..7 {
+            assert_eq!(F7::new(a) * F7::new(a).inverse().unwrap(), F7::new(1));
+        }
+        assert_eq!(F7::new(0).inverse(), None);
+        assert_eq!(ModP::<8>::new(2).inverse(), None);
+        assert_eq!(ModP::<8>::new(3).inverse(), Some(ModP::new(3)));
+    }
+}
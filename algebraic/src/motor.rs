@@ -0,0 +1,325 @@
+//! This module contains [Motor], the rigid-body transformations of 3D
+//! [PGA](crate::pga) in compact form.
+//!
+//! A motor is an element of the even subalgebra of [PgaMultiVector], i.e. only the scalar,
+//! bivector, and pseudoscalar coefficients. It is built from [PgaMultiVector] parts, e.g.
+//! [rotors](PgaMultiVector::rotor), [translators](PgaMultiVector::translator), or the
+//! [exponential](Motor::exp) of a bivector, and moves points, lines, and planes with
+//! [apply](Motor::apply). The [logarithm](Motor::log) gives the screw (the bivector) back, which
+//! [sclerp](Motor::sclerp) uses to interpolate with constant speed along the screw.
+//!
+//! ```
+//! # use algebraic::{Motor, PgaMultiVector};
+//! use std::f64::consts::PI;
+//!
+//! let start = Motor::from(PgaMultiVector::translator(0.0, 0.0, 0.0));
+//! let end = Motor::from(PgaMultiVector::motor([0.0, 0.0, 2.0], [0.0, 0.0, 1.0], PI / 2.0));
+//! let halfway = start.sclerp(end, 0.5).apply(PgaMultiVector::point(1.0, 0.0, 0.0));
+//! let [x, y, z] = halfway.point_coordinates().unwrap();
+//! let expected = [(PI / 4.0).cos(), (PI / 4.0).sin(), 1.0];
+//! assert!([x, y, z].iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-12));
+//! ```
+use crate::{multi_vector::grade_part, MultiVector, PgaMultiVector, Ring, RingMultiVector, Scalar};
+use std::ops::Mul;
+
+// indices of the even blades of `PgaMultiVector`, in storage order
+const EVEN: [usize; 8] = [0, 0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100, 0b1111];
+const EUCLIDEAN: [usize; 3] = [0b0011, 0b0101, 0b0110];
+const PSEUDOSCALAR: usize = 0b1111;
+
+/// The even part of a [PgaMultiVector], i.e. the coefficients of `1`, `e12`, `e13`, `e23`,
+/// `e14`, `e24`, `e34`, and `e1234` (with the degenerate `e4`).
+///
+/// Normalized motors, i.e. `motor * reverse(motor) == 1`, are rigid-body transformations.
+/// [Mul] composes them, `a * b` applies `b` first.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Motor<T>(pub [T; 8]);
+
+/// Drops the odd part.
+impl<T: Ring> From<PgaMultiVector<T>> for Motor<T> {
+    fn from(a: PgaMultiVector<T>) -> Self {
+        Self(EVEN.map(|i| a[i]))
+    }
+}
+
+impl<T: Ring> From<Motor<T>> for PgaMultiVector<T> {
+    fn from(motor: Motor<T>) -> Self {
+        let mut a = Self::default();
+        for (i, x) in EVEN.into_iter().zip(motor.0) {
+            a[i] = x;
+        }
+        a
+    }
+}
+
+impl<T: Ring> Mul for Motor<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from(PgaMultiVector::from(self) * PgaMultiVector::from(rhs))
+    }
+}
+
+impl<T: Ring> Motor<T> {
+    /// Reverses the order of the vectors, the inverse of normalized motors.
+    pub fn reverse(self) -> Self {
+        Self::from(PgaMultiVector::from(self).reverse())
+    }
+}
+
+impl<T: Scalar<Real = T>> Motor<T> {
+    /// The identity, i.e. `1`.
+    pub fn identity() -> Self {
+        Self::from(Self::scalar(T::one()))
+    }
+
+    fn scalar(x: T) -> PgaMultiVector<T> {
+        let mut a = PgaMultiVector::default();
+        a[0] = x;
+        a
+    }
+
+    fn pseudoscalar(x: T) -> PgaMultiVector<T> {
+        let mut a = PgaMultiVector::default();
+        a[PSEUDOSCALAR] = x;
+        a
+    }
+
+    // `self * reverse(self)`, only scalar and pseudoscalar parts
+    fn squared_norm(self) -> (T, T) {
+        let a = PgaMultiVector::from(self * self.reverse());
+        (a[0], a[PSEUDOSCALAR])
+    }
+
+    /// The inverse wrt. the geometric product, `None` for pure ideal elements, i.e. without
+    /// scalar and Euclidean bivector parts.
+    pub fn inverse(self) -> Option<Self> {
+        // `1 / (s + p e1234) = (s - p e1234) / s²`, as `e1234` squares to `0`
+        let (s, p) = self.squared_norm();
+        (s != T::zero()).then(|| {
+            let inverse = Self::scalar(T::one() / s) - Self::pseudoscalar(p / (s * s));
+            Self::from(PgaMultiVector::from(self.reverse()) * inverse)
+        })
+    }
+
+    /// Scaled to `self * reverse(self) == 1`.
+    pub fn normalized(self) -> Self {
+        // `1 / sqrt(s + p e1234) = (1 - p / 2s e1234) / sqrt(s)`
+        let (s, p) = self.squared_norm();
+        let factor = Self::scalar(T::one()) - Self::pseudoscalar(p / (s + s));
+        Self::from(PgaMultiVector::from(self) * factor / s.sqrt())
+    }
+
+    /// `self * x * reverse(self)`, moves the point, line, or plane `x`.
+    pub fn apply(self, x: PgaMultiVector<T>) -> PgaMultiVector<T> {
+        PgaMultiVector::from(self).sandwich(x)
+    }
+
+    /// The exponential of the bivector part of `bivector`, i.e. the motor along the screw
+    /// `bivector`.
+    ///
+    /// Closed form: `B² = -θ² - 2θδ e1234` for a bivector `B`, and `B / (θ + δ e1234)` squares to
+    /// `-1`, so `exp(B)` is the cosine plus the sine (of dual numbers) times that unit.
+    pub fn exp(bivector: PgaMultiVector<T>) -> Self {
+        let bivector = grade_part(bivector, 2);
+        let square = bivector * bivector;
+        if square[0] == T::zero() {
+            // a translation, the bivector squares to `0`
+            return Self::from(Self::scalar(T::one()) + bivector);
+        }
+        let theta = (-square[0]).sqrt();
+        let delta = -square[PSEUDOSCALAR] / (theta + theta);
+        let (sin, cos) = (theta.sin(), theta.cos());
+        let cosine = Self::scalar(cos) - Self::pseudoscalar(delta * sin);
+        let sine =
+            Self::scalar(sin / theta) + Self::pseudoscalar(delta * (cos - sin / theta) / theta);
+        Self::from(cosine + sine * bivector)
+    }
+
+    /// The logarithm of a normalized motor, a bivector with `Motor::exp(self.log()) == self`.
+    ///
+    /// The rotation angle, twice the angle of the result, is in `[0, 2π]`.
+    pub fn log(self) -> PgaMultiVector<T> {
+        let a = PgaMultiVector::from(self);
+        let bivector = grade_part(a, 2);
+        let mut euclidean = PgaMultiVector::default();
+        for i in EUCLIDEAN {
+            euclidean[i] = bivector[i];
+        }
+        let sin = (euclidean * euclidean.reverse())[0].sqrt();
+        let theta = sin.atan2(a[0]);
+        if sin == T::zero() {
+            if theta == T::zero() {
+                // a translation
+                return bivector;
+            }
+            // `-1 + t` for an ideal `t` is a full turn around the axis along `t`, i.e. `π b/|b| - t`
+            // with the Euclidean `b` for which `e1234 b` is `-t`
+            let mut axis = PgaMultiVector::default();
+            for i in EUCLIDEAN {
+                let ideal = Self::pseudoscalar(T::one()) * PgaMultiVector::base_element(i);
+                axis[i] = ideal
+                    .into_iter()
+                    .zip(bivector)
+                    .fold(T::zero(), |sum, (x, y)| sum - x * y);
+            }
+            let mut length = (axis * axis.reverse())[0].sqrt();
+            if length == T::zero() {
+                // `-1`, any axis will do
+                axis[EUCLIDEAN[0]] = T::one();
+                length = T::one();
+            }
+            return axis * (theta / length) - bivector;
+        }
+        let delta = -a[PSEUDOSCALAR] / sin;
+        // the ideal part of `(θ + δ e1234) / sin(θ + δ e1234) * bivector`
+        let ideal = delta * (T::one() - theta * a[0] / sin) / sin;
+        bivector * (theta / sin) + Self::pseudoscalar(ideal) * euclidean
+    }
+
+    /// Screw-linear interpolation of normalized motors, from `self` at `t = 0` to `other` at
+    /// `t = 1`, the shorter way around.
+    pub fn sclerp(self, other: Self, t: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        let mut relative = self.reverse() * other;
+        // `-relative` is the same transformation
+        if relative.0[0] < T::zero() {
+            relative = Self(relative.0.map(|x| -x));
+        }
+        self * Self::exp(relative.log() * t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MultiVector;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    type Pga = PgaMultiVector<f64>;
+
+    fn close(a: Motor<f64>, b: Motor<f64>) -> bool {
+        a.0.iter().zip(b.0).all(|(a, b)| (a - b).abs() < 1e-12)
+    }
+
+    fn coordinates(p: Pga) -> [f64; 3] {
+        p.point_coordinates().unwrap()
+    }
+
+    fn close_points(a: [f64; 3], b: [f64; 3]) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-12)
+    }
+
+    fn screw() -> Motor<f64> {
+        Motor::from(Pga::motor([1.0, -1.0, 2.0], [1.0, 2.0, 2.0], 2.0))
+    }
+
+    #[test]
+    fn composition() {
+        let (a, b) = (screw(), Motor::from(Pga::rotor([0.0, 1.0, 0.0], FRAC_PI_2)));
+        let p = Pga::point(1.0, 2.0, 3.0);
+        let expected = Pga::from(a) * Pga::from(b);
+        assert_eq!(Pga::from(a * b), expected);
+        assert!(close_points(
+            coordinates((a * b).apply(p)),
+            coordinates(a.apply(b.apply(p)))
+        ));
+
+        let plane = Pga::plane(1.0, 0.0, 0.0, -1.0);
+        let line = Pga::line([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]);
+        assert!(a
+            .apply(plane)
+            .meet(a.apply(line))
+            .meet(a.apply(Pga::point(1.0, 5.0, 0.0)))
+            .into_iter()
+            .all(|x| x.abs() < 1e-12));
+    }
+
+    #[test]
+    fn inverse() {
+        let motor = screw();
+        assert!(close(motor.inverse().unwrap(), motor.reverse()));
+        assert!(close(motor * motor.reverse(), Motor::identity()));
+
+        // scaled, and with a pseudoscalar part
+        let mut scaled = motor;
+        scaled.0 = scaled.0.map(|x| 3.0 * x);
+        scaled.0[7] += 0.5;
+        assert!(close(scaled * scaled.inverse().unwrap(), Motor::identity()));
+        assert!(close(
+            scaled.normalized() * scaled.normalized().reverse(),
+            Motor::identity()
+        ));
+        // a pure translation part
+        assert_eq!(
+            Motor([0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]).inverse(),
+            None
+        );
+    }
+
+    #[test]
+    fn exp_log() {
+        let rotor = Pga::rotor([0.0, 0.0, 1.0], 1.5);
+        let mut generator = Pga::default();
+        generator[0b0011] = -0.75;
+        assert!(close(Motor::exp(generator), Motor::from(rotor)));
+
+        let mut generator = Pga::default();
+        generator[0b1001] = 1.5;
+        assert!(close(
+            Motor::exp(generator),
+            Motor::from(Pga::translator(3.0, 0.0, 0.0))
+        ));
+        assert!(
+            (Motor::from(Pga::translator(3.0, 0.0, 0.0)).log() - generator)
+                .into_iter()
+                .all(|x| x.abs() < 1e-12)
+        );
+
+        // full turns, the negated identity and translator
+        let negate = |motor: Motor<f64>| Motor(motor.0.map(|x| -x));
+        let translator = Motor::from(Pga::translator(3.0, -1.0, 2.0));
+        for motor in [negate(Motor::identity()), negate(translator)] {
+            assert!(close(Motor::exp(motor.log()), motor));
+            let p = Pga::point(1.0, 2.0, 3.0);
+            assert!(close_points(
+                coordinates(motor.apply(p)),
+                coordinates(negate(motor).apply(p))
+            ));
+        }
+
+        for motor in [screw(), Motor::from(rotor), Motor::identity()] {
+            assert!(close(Motor::exp(motor.log()), motor));
+            // agrees with the Taylor series
+            assert!(close(Motor::from(motor.log().exp()), motor));
+        }
+    }
+
+    #[test]
+    fn sclerp() {
+        let (a, b) = (Motor::identity(), screw());
+        assert!(close(a.sclerp(b, 0.0), a));
+        assert!(close(a.sclerp(b, 1.0), b));
+
+        // constant speed along the screw: rotating by π around z while moving up by 2
+        let end = Motor::from(Pga::motor([0.0, 0.0, 2.0], [0.0, 0.0, 1.0], PI));
+        let p = Pga::point(1.0, 0.0, 0.0);
+        for i in 0..=4 {
+            let t = i as f64 / 4.0;
+            let angle = PI * t;
+            let moved = coordinates(a.sclerp(end, t).apply(p));
+            assert!(close_points(moved, [angle.cos(), angle.sin(), 2.0 * t]));
+        }
+
+        // the shorter way, `-end` is the same motion
+        let negated = Motor(end.0.map(|x| -x));
+        let halfway = coordinates(a.sclerp(negated, 0.5).apply(p));
+        assert!(close_points(halfway, [0.0, 1.0, 1.0]));
+        let rotor = Motor::from(Pga::rotor([0.0, 0.0, 1.0], 1.5 * PI));
+        let halfway = coordinates(a.sclerp(rotor, 0.5).apply(p));
+        let angle = -0.25 * PI;
+        assert!(close_points(halfway, [angle.cos(), angle.sin(), 0.0]));
+    }
+}
@@ -1,29 +1,42 @@
-//! This module contains the [MultiVector] trait and it's implementations.
+//! This module contains the [RingMultiVector] and [MultiVector] traits and their
+//! implementations.
 //!
 //! Also note the [geometric_product_0], .., [geometric_product_6]. They are used for the [Mul]
 //! implementation of the respective [MultiVector0], .., [MultiVector6]. The attached documentaiton
 //! also shows which index corresponds to which base ceofficient.
+use crate::{
+    display::format_blades,
+    parse::{parse_blades, ParseError},
+    Ring, Scalar,
+};
 use algebraic_gen::generate_geometric_product;
 use std::{
     borrow::{Borrow, BorrowMut},
     convert::{AsMut, AsRef},
-    fmt::Debug,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
     ops::{Add, Div, Index, IndexMut, Mul, Sub},
+    str::FromStr,
 };
 
-/// Generalization of multi vectors of geometric algebras of different dimensions.
-/// (Currently `0` to `6` dimensions)
+/// Generalization of multi vectors of geometric algebras of different dimensions over a [Ring].
+/// (Currently `0` to `6` dimensions, and [PgaMultiVector], [CgaMultiVector], and
+/// [StaMultiVector] with other signatures)
 ///
-/// All implementations are just light wrappers around arrays of size `BASE_SIZE`. The
-/// implementations for [Copy], [Debug], [IntoIterator], [IndexMut], [AsRef], [AsMut], and
-/// [BorrowMut] are all 'just forwarded' from the underlying array.
+/// All implementations are just light `#[repr(transparent)]` wrappers around arrays of size
+/// `BASE_SIZE`, i.e. they have the same layout. The
+/// implementations for [Copy], [Debug], [PartialEq], [Eq], [Hash], [IntoIterator], [IndexMut],
+/// [AsRef], [AsMut], and [BorrowMut] are all 'just forwarded' from the underlying array.
 /// ([Default] isn't implemented for arrays larger than 32, using `[T::default(); BASE_SIZE]`
 /// instead.)
 ///
 /// [Add] and [Sub] are per-coefficient, while [Mul] with another instance calls the function
-/// generated with [generate_geometric_product]. For convinience and performance, [Mul] (and
-/// [Div]) have special implementations for scalars.
-pub trait MultiVector<T>:
+/// generated with [generate_geometric_product]. For convinience and performance, [Mul] has a
+/// special implementation for scalars.
+///
+/// This only needs the coefficients to be added, subtracted, and multiplied, so integers or
+/// [ModP](crate::ModP) work as well. [MultiVector] adds everything that needs real numbers.
+pub trait RingMultiVector<T: Ring>:
     // forwarded from array
     Copy
     + Debug
@@ -39,7 +52,6 @@ pub trait MultiVector<T>:
     + Sub<Output = Self>
     + Mul<Output = Self>
     + Mul<T, Output = Self>
-    + Div<T, Output = Self>
     + private::Sealed
 {
     /// The dimension of the geometric algebra
@@ -47,6 +59,141 @@ pub trait MultiVector<T>:
 
     /// The size of the base: `2^ALGEBRA_DIMENSION`
     const BASE_SIZE: usize = 1 << Self::ALGEBRA_DIMENSION;
+
+    /// Reverses the order of the vectors in each base element, i.e. the coefficients of
+    /// elements with `k` vectors are negated if `k(k-1)/2` is odd.
+    fn reverse(self) -> Self {
+        let mut reverse = self;
+        for i in 0..Self::BASE_SIZE {
+            // reversing k vectors takes k(k-1)/2 swaps
+            if i.count_ones() % 4 >= 2 {
+                reverse[i] = -self[i];
+            }
+        }
+        reverse
+    }
+}
+
+/// [RingMultiVector] with [Scalar] coefficients.
+///
+/// [Div] by scalars is per-coefficient. On top of that, there are provided methods for the
+/// [inverse](MultiVector::inverse), the [exponential](MultiVector::exp), and the
+/// [norm](MultiVector::norm).
+pub trait MultiVector<T: Scalar>: RingMultiVector<T> + Div<T, Output = Self> {
+    /// The inverse wrt. the geometric product, if there is one.
+    ///
+    /// Solves `self * inverse == 1` with Gaussian elimination (partial pivoting), `None` is
+    /// returned if a pivot is exactly zero.
+    fn inverse(self) -> Option<Self> {
+        let zero = T::zero();
+
+        // columns are the products with the base elements, last column is the scalar one
+        let mut matrix: Vec<Vec<T>> = (0..Self::BASE_SIZE)
+            .map(|r| {
+                (0..Self::BASE_SIZE)
+                    .map(|c| (self * Self::base_element(c))[r])
+                    .chain([if r == 0 { T::one() } else { zero }])
+                    .collect()
+            })
+            .collect();
+
+        for c in 0..Self::BASE_SIZE {
+            let pivot = (c..Self::BASE_SIZE)
+                .reduce(|p, r| {
+                    if matrix[r][c].abs() > matrix[p][c].abs() {
+                        r
+                    } else {
+                        p
+                    }
+                })
+                .unwrap();
+            if matrix[pivot][c] == zero {
+                return None;
+            }
+            matrix.swap(c, pivot);
+            let pivot_row = matrix[c].clone();
+            for (_, row) in matrix.iter_mut().enumerate().filter(|(r, _)| *r != c) {
+                let factor = row[c] / pivot_row[c];
+                for (x, p) in row[c..].iter_mut().zip(&pivot_row[c..]) {
+                    *x = *x - factor * *p;
+                }
+            }
+        }
+
+        let mut inverse = Self::default();
+        for r in 0..Self::BASE_SIZE {
+            inverse[r] = matrix[r][Self::BASE_SIZE] / matrix[r][r];
+        }
+        Some(inverse)
+    }
+
+    /// The exponential wrt. the geometric product.
+    ///
+    /// Scales down until the sum of absolute coefficients is at most `0.5`, sums the Taylor
+    /// series until it doesn't change anymore, and squares back up.
+    fn exp(self) -> Self {
+        let l1_norm = |mv: Self| mv.into_iter().fold(T::Real::zero(), |sum, x| sum + x.abs());
+
+        let mut scaled = self;
+        let mut squarings = 0;
+        // the l1 norm is submultiplicative, this bounds the terms of the series
+        while l1_norm(scaled) > T::Real::from_f64(0.5) && squarings < 1024 {
+            scaled = scaled * T::from_f64(0.5);
+            squarings += 1;
+        }
+
+        let mut sum = Self::base_element(0);
+        let mut term = sum;
+        for k in 1..32 {
+            term = term * scaled / T::from_f64(k as f64);
+            let next = sum + term;
+            if next.into_iter().zip(sum).all(|(n, s)| n == s) {
+                break;
+            }
+            sum = next;
+        }
+
+        for _ in 0..squarings {
+            sum = sum * sum;
+        }
+        sum
+    }
+
+    /// The Euclidean norm of the coefficients, i.e. the square root of the sum of their squared
+    /// absolute values.
+    fn norm(self) -> T::Real {
+        self.into_iter()
+            .map(T::abs)
+            .fold(T::Real::zero(), |sum, x| sum + x * x)
+            .sqrt()
+    }
+
+    /// The base element with the given index, i.e. coefficient `1` at `index`, `0` elsewhere.
+    fn base_element(index: usize) -> Self {
+        let mut element = Self::default();
+        element[index] = T::one();
+        element
+    }
+}
+
+// the squares of the basis vectors, `-1` unless there is a signature
+macro_rules! squares {
+    ($dimension:literal) => {
+        &[-1; $dimension]
+    };
+    ($dimension:literal, $squares:tt) => {
+        &$squares
+    };
+}
+
+// the dimension is passed on unless there is a signature
+macro_rules! geometric_product {
+    ($product:ident, $dimension:literal) => {
+        generate_geometric_product!($product, $dimension);
+    };
+    ($product:ident, $dimension:literal, $squares:tt) => {
+        generate_geometric_product!($product, $squares);
+    };
 }
 
 macro_rules! generate_multivector_boilerplate {
@@ -55,10 +202,12 @@ macro_rules! generate_multivector_boilerplate {
         $name:ident,
         $product:ident,
         $dimension:literal,
+        $(signature = $squares:tt,)?
         $($idx:literal),+
     ) => {
 
         $(#[$outer])*
+        #[repr(transparent)]
         pub struct $name<T>(pub [T; 1 << $dimension]);
 
         impl<T> IntoIterator for $name<T> {
@@ -120,6 +269,119 @@ macro_rules! generate_multivector_boilerplate {
             }
         }
 
+        impl<T: PartialEq> PartialEq for $name<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<T: Eq> Eq for $name<T> {}
+
+        impl<T: Hash> Hash for $name<T> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
+        /// Per coefficient, see [approx](https://docs.rs/approx/latest/approx).
+        #[cfg(feature = "approx")]
+        impl<T: approx::AbsDiffEq> approx::AbsDiffEq for $name<T>
+        where
+            T::Epsilon: Copy,
+        {
+            type Epsilon = T::Epsilon;
+
+            fn default_epsilon() -> T::Epsilon {
+                T::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                self.0[..].abs_diff_eq(&other.0[..], epsilon)
+            }
+        }
+
+        /// Per coefficient, see [approx](https://docs.rs/approx/latest/approx).
+        #[cfg(feature = "approx")]
+        impl<T: approx::RelativeEq> approx::RelativeEq for $name<T>
+        where
+            T::Epsilon: Copy,
+        {
+            fn default_max_relative() -> T::Epsilon {
+                T::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+                self.0[..].relative_eq(&other.0[..], epsilon, max_relative)
+            }
+        }
+
+        /// Per coefficient, see [approx](https://docs.rs/approx/latest/approx).
+        #[cfg(feature = "approx")]
+        impl<T: approx::UlpsEq> approx::UlpsEq for $name<T>
+        where
+            T::Epsilon: Copy,
+        {
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                self.0[..].ulps_eq(&other.0[..], epsilon, max_ulps)
+            }
+        }
+
+        /// Sum of the non-zero coefficients with their blade names, e.g. `1 + 2e1 - 0.5e12`.
+        /// The blades are named as in the documentation of the product. Precision is applied to
+        /// each coefficient, the alternate form `{:#}` groups the terms by grade, e.g.
+        /// `⟨1⟩₀ + ⟨2e1 + 3e2⟩₁ + ⟨-0.5e12⟩₂`.
+        impl<T: Display + Default + PartialEq> Display for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                format_blades(&self.0, f)
+            }
+        }
+
+        /// Parses sums of blades like `1 + 2e1 - 0.5e12`, see [parse](crate::parse) for the
+        /// details. Note that the alternate [Display] form isn't parsed.
+        impl<T> FromStr for $name<T>
+        where
+            T: Copy + Default + FromStr + Add<T, Output = T> + Sub<T, Output = T>,
+        {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut multi_vector = Self([T::default(); 1 << $dimension]);
+                parse_blades(s, squares!($dimension $(, $squares)?), &mut multi_vector.0)?;
+                Ok(multi_vector)
+            }
+        }
+
+        /// As an array of all coefficients, see [serialization](crate::serialization).
+        #[cfg(feature = "serde")]
+        impl<T: serde::Serialize> serde::Serialize for $name<T> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serialization::serialize_array(&self.0, serializer)
+            }
+        }
+
+        /// From an array of exactly `BASE_SIZE` coefficients, see
+        /// [serialization](crate::serialization).
+        #[cfg(feature = "serde")]
+        impl<'de, T: Copy + Default + serde::Deserialize<'de>> serde::Deserialize<'de> for $name<T> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let mut multi_vector = Self([T::default(); 1 << $dimension]);
+                crate::serialization::deserialize_array(deserializer, &mut multi_vector.0)?;
+                Ok(multi_vector)
+            }
+        }
+
+        /// All zero bytes are all zero coefficients.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for $name<T> {}
+
+        /// `#[repr(transparent)]` over an array of [Pod](bytemuck::Pod)s, so there is no padding.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<T: bytemuck::Pod> bytemuck::Pod for $name<T> {}
+
         /// Arrays implement Default only up to size 32.
         /// So, this is using the inner Default.
         impl<T: Copy + Default> Default for $name<T> {
@@ -164,7 +426,7 @@ macro_rules! generate_multivector_boilerplate {
             }
         }
 
-        generate_geometric_product!($product, $dimension);
+        geometric_product!($product, $dimension $(, $squares)?);
 
         /// This is implemented via [generate_geometric_product].
         impl<T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T>> Mul<Self>
@@ -177,19 +439,38 @@ macro_rules! generate_multivector_boilerplate {
             }
         }
 
-        /// Apart from the [MultiVector::ALGEBRA_DIMENSION] there is nothing to implement directly.
-        impl<
-                T: Copy
-                    + Default
-                    + Debug
-                    + Add<T, Output = T>
-                    + Sub<T, Output = T>
-                    + Mul<T, Output = T>
-                    + Div<T, Output = T>,
-            > MultiVector<T> for $name<T>
+        /// All coefficients zero.
+        #[cfg(feature = "num-traits")]
+        impl<T: Copy + num_traits::Zero> num_traits::Zero for $name<T> {
+            fn zero() -> Self {
+                Self([T::zero(); 1 << $dimension])
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0.iter().all(T::is_zero)
+            }
+        }
+
+        /// The scalar base element, the neutral element of the geometric product.
+        #[cfg(feature = "num-traits")]
+        impl<T> num_traits::One for $name<T>
+        where
+            T: Copy + num_traits::Zero + num_traits::One + Sub<T, Output = T>,
         {
+            fn one() -> Self {
+                let mut one = Self([T::zero(); 1 << $dimension]);
+                one[0] = T::one();
+                one
+            }
+        }
+
+        /// Apart from the [RingMultiVector::ALGEBRA_DIMENSION] there is nothing to implement
+        /// directly.
+        impl<T: Ring> RingMultiVector<T> for $name<T> {
             const ALGEBRA_DIMENSION: usize = $dimension;
         }
+
+        impl<T: Scalar> MultiVector<T> for $name<T> {}
     };
 }
 
@@ -376,6 +657,131 @@ generate_multivector_boilerplate!(
     63
 );
 
+generate_multivector_boilerplate!(
+    /// Element of the projective geometric algebra `Cl(3,0,1)` of 3D space.
+    /// The basis vectors `e1`, `e2`, and `e3` square to `1`, `e4` squares to `0`, see
+    /// [pga](crate::pga).
+    PgaMultiVector,
+    geometric_product_pga,
+    4,
+    signature = [1, 1, 1, 0],
+    0,
+    1,
+    2,
+    3,
+    4,
+    5,
+    6,
+    7,
+    8,
+    9,
+    10,
+    11,
+    12,
+    13,
+    14,
+    15
+);
+
+generate_multivector_boilerplate!(
+    /// Element of the conformal geometric algebra `Cl(4,1)` of 3D space.
+    /// The basis vectors `e1` to `e4` square to `1`, `e5` squares to `-1`, see [cga](crate::cga).
+    CgaMultiVector,
+    geometric_product_cga,
+    5,
+    signature = [1, 1, 1, 1, -1],
+    0,
+    1,
+    2,
+    3,
+    4,
+    5,
+    6,
+    7,
+    8,
+    9,
+    10,
+    11,
+    12,
+    13,
+    14,
+    15,
+    16,
+    17,
+    18,
+    19,
+    20,
+    21,
+    22,
+    23,
+    24,
+    25,
+    26,
+    27,
+    28,
+    29,
+    30,
+    31
+);
+
+generate_multivector_boilerplate!(
+    /// Element of the spacetime algebra `Cl(1,3)`.
+    /// The basis vector `e1` (`γ0`) squares to `1`, `e2`, `e3`, and `e4` (`γ1` to `γ3`) square
+    /// to `-1`, see [sta](crate::sta).
+    StaMultiVector,
+    geometric_product_sta,
+    4,
+    signature = [1, -1, -1, -1],
+    0,
+    1,
+    2,
+    3,
+    4,
+    5,
+    6,
+    7,
+    8,
+    9,
+    10,
+    11,
+    12,
+    13,
+    14,
+    15
+);
+
+/// The part of grade `k`, i.e. the coefficients of elements with `k` basis vectors.
+pub(crate) fn grade_part<T: Ring, M: RingMultiVector<T>>(a: M, k: usize) -> M {
+    let mut part = M::default();
+    for i in (0..M::BASE_SIZE).filter(|i| i.count_ones() as usize == k) {
+        part[i] = a[i];
+    }
+    part
+}
+
+// sums the grade `grade(r, s)` parts of the products of the grade `r` and `s` parts
+fn graded_product<T: Ring, M: RingMultiVector<T>>(
+    a: M,
+    b: M,
+    grade: impl Fn(usize, usize) -> usize,
+) -> M {
+    let parts = |m: M| (0..=M::ALGEBRA_DIMENSION).map(move |k| (k, grade_part(m, k)));
+    parts(a).fold(M::default(), |sum, (r, a)| {
+        parts(b).fold(sum, |sum, (s, b)| sum + grade_part(a * b, grade(r, s)))
+    })
+}
+
+/// The outer (wedge) product via the geometric product, `⟨⟨a⟩ᵣ⟨b⟩ₛ⟩ᵣ₊ₛ` summed over the grades.
+pub(crate) fn outer_product<T: Ring, M: RingMultiVector<T>>(a: M, b: M) -> M {
+    graded_product(a, b, |r, s| r + s)
+}
+
+/// The symmetric inner product via the geometric product, `⟨⟨a⟩ᵣ⟨b⟩ₛ⟩|ᵣ₋ₛ|` summed over the
+/// grades.
+pub(crate) fn inner_product<T: Ring, M: RingMultiVector<T>>(a: M, b: M) -> M {
+    graded_product(a, b, |r, s| r.abs_diff(s))
+}
+
 mod private {
     pub trait Sealed {}
     impl<T> Sealed for super::MultiVector0<T> {}
@@ -385,4 +791,7 @@ mod private {
     impl<T> Sealed for super::MultiVector4<T> {}
     impl<T> Sealed for super::MultiVector5<T> {}
     impl<T> Sealed for super::MultiVector6<T> {}
+    impl<T> Sealed for super::PgaMultiVector<T> {}
+    impl<T> Sealed for super::CgaMultiVector<T> {}
+    impl<T> Sealed for super::StaMultiVector<T> {}
 }
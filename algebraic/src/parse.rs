@@ -0,0 +1,323 @@
+//! Parsing of multi vectors from sums of blades, the counterpart to [display](crate::display).
+//!
+//! A string like `1 + 2e1 - 0.5e12` is a sum of terms, each is a coefficient followed by a blade
+//! name. Either may be omitted, the coefficient defaults to `1`, the blade to the scalar. The
+//! basis vectors of a blade need not be ordered or unique, e.g. `e21` is `-e12`, and repeated ones
+//! square according to the signature of the type (see [canonize_with_signature]), e.g. `e11` is
+//! `-1` in the default signature, `1` for [PgaMultiVector](crate::PgaMultiVector), and `e44` is
+//! `0` for the latter. Terms with the same blade are summed.
+//!
+//! Plain coefficients consist of digits and `.`, anything else (exponents, `inf`, complex
+//! numbers, ..) goes into parentheses, e.g. `(1e-3)e12`. The content is parsed by [FromStr].
+//! Above nine dimensions, the indices of a blade are separated by underscores, e.g. `e1_10`.
+use algebraic_core::{canonize_with_signature, Signature};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+/// What went wrong, see [ParseError].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// There are no terms at all.
+    Empty,
+    /// Neither a term nor a sign where one is expected.
+    UnexpectedToken,
+    /// The coefficient's [FromStr] failed.
+    InvalidCoefficient,
+    /// The blade has no indices, or one that is out of range for the dimension.
+    InvalidBlade,
+}
+
+/// Error of the [FromStr] implementations, pointing at the failing token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    /// Byte offset of the token in the parsed string.
+    pub position: usize,
+    pub token: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let what = match self.kind {
+            ParseErrorKind::Empty => return write!(f, "empty multi vector expression"),
+            ParseErrorKind::UnexpectedToken => "unexpected",
+            ParseErrorKind::InvalidCoefficient => "invalid coefficient",
+            ParseErrorKind::InvalidBlade => "invalid blade",
+        };
+        write!(f, "{what} `{}` at position {}", self.token, self.position)
+    }
+}
+
+impl Error for ParseError {}
+
+struct Parser<'a> {
+    string: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &str {
+        &self.string[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.position = self.string.len() - self.rest().trim_start().len();
+    }
+
+    // consumes the longest prefix matching `accept`
+    fn take_while(&mut self, accept: impl Fn(char) -> bool) -> &'a str {
+        let start = self.position;
+        let length = self
+            .rest()
+            .find(|c| !accept(c))
+            .unwrap_or(self.rest().len());
+        self.position += length;
+        &self.string[start..self.position]
+    }
+
+    fn error(&self, kind: ParseErrorKind, position: usize, token: &str) -> ParseError {
+        ParseError {
+            kind,
+            position,
+            token: token.to_string(),
+        }
+    }
+
+    // the word at the current position, for error messages
+    fn unexpected(&self) -> ParseError {
+        let word = self
+            .rest()
+            .split(|c: char| c.is_whitespace() || c == '+' || c == '-')
+            .next()
+            .unwrap_or_default();
+        let token = match (word, self.peek()) {
+            ("", Some(c)) => c.to_string(),
+            ("", None) => "end of input".to_string(),
+            (word, _) => word.to_string(),
+        };
+        self.error(ParseErrorKind::UnexpectedToken, self.position, &token)
+    }
+
+    fn coefficient<T: FromStr>(&mut self) -> Result<Option<T>, ParseError> {
+        let start = self.position;
+        let token = if self.peek() == Some('(') {
+            let mut depth = 0;
+            let length = self
+                .rest()
+                .char_indices()
+                .find(|(_, c)| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                })
+                .map(|(i, _)| i + 1)
+                .ok_or_else(|| self.error(ParseErrorKind::UnexpectedToken, start, self.rest()))?;
+            self.position += length;
+            &self.string[start + 1..self.position - 1]
+        } else {
+            self.take_while(|c| c.is_ascii_digit() || c == '.')
+        };
+        if self.position == start {
+            return Ok(None);
+        }
+        token.trim().parse().map(Some).map_err(|_| {
+            self.error(
+                ParseErrorKind::InvalidCoefficient,
+                start,
+                &self.string[start..self.position],
+            )
+        })
+    }
+
+    fn blade(&mut self, dimension: usize) -> Result<Option<Vec<usize>>, ParseError> {
+        let start = self.position;
+        if self.peek() != Some('e') {
+            return Ok(None);
+        }
+        self.position += 1;
+        let indices = self.take_while(|c| c.is_ascii_digit() || c == '_');
+        let indices = if indices.contains('_') {
+            indices.split('_').map(str::parse::<usize>).collect()
+        } else {
+            indices
+                .chars()
+                .map(|c| c.to_string().parse::<usize>())
+                .collect::<Result<Vec<_>, _>>()
+        };
+        match indices {
+            Ok(indices)
+                if !indices.is_empty() && indices.iter().all(|i| (1..=dimension).contains(i)) =>
+            {
+                Ok(Some(indices.iter().map(|i| i - 1).collect()))
+            }
+            _ => Err(self.error(
+                ParseErrorKind::InvalidBlade,
+                start,
+                &self.string[start..self.position],
+            )),
+        }
+    }
+}
+
+/// Adds the terms of the string to the coefficients, with the basis vectors squaring to
+/// `squares`, see the [FromStr] implementations.
+pub(crate) fn parse_blades<T>(
+    string: &str,
+    squares: &[i8],
+    coefficients: &mut [T],
+) -> Result<(), ParseError>
+where
+    T: Copy + FromStr + Add<T, Output = T> + Sub<T, Output = T>,
+{
+    let dimension = coefficients.len().trailing_zeros() as usize;
+    let signature = Signature::from_squares(squares.to_vec());
+    let mut parser = Parser {
+        string,
+        position: 0,
+    };
+    let mut first = true;
+    loop {
+        parser.skip_whitespace();
+        if first && parser.peek().is_none() {
+            return Err(parser.error(ParseErrorKind::Empty, 0, ""));
+        }
+        let negative = match parser.peek() {
+            Some(sign @ ('+' | '-')) => {
+                parser.position += 1;
+                sign == '-'
+            }
+            _ if first => false,
+            _ => return Err(parser.unexpected()),
+        };
+        parser.skip_whitespace();
+
+        let start = parser.position;
+        let coefficient = parser.coefficient::<T>()?;
+        let blade = parser.blade(dimension)?;
+        if parser.position == start {
+            return Err(parser.unexpected());
+        }
+        let coefficient = match coefficient {
+            Some(coefficient) => coefficient,
+            None => "1"
+                .parse()
+                .map_err(|_| parser.error(ParseErrorKind::InvalidCoefficient, start, "1"))?,
+        };
+        let mut element = blade.unwrap_or_default();
+        // `None` if a repeated basis vector squares to `0`, i.e. the term is zero
+        if let Some(negate) = canonize_with_signature(&mut element, &signature) {
+            let negative = negative ^ negate;
+            let index = element.iter().map(|d| 1 << d).sum::<usize>();
+            coefficients[index] = if negative {
+                coefficients[index] - coefficient
+            } else {
+                coefficients[index] + coefficient
+            };
+        }
+
+        first = false;
+        parser.skip_whitespace();
+        if parser.peek().is_none() {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiVector2, MultiVector3, MultiVector4};
+
+    #[test]
+    fn blades() {
+        assert_eq!(
+            "1 + 2e1 - 0.5e12 + 3e123".parse(),
+            Ok(MultiVector3([1.0, 2.0, 0.0, -0.5, 0.0, 0.0, 0.0, 3.0]))
+        );
+        assert_eq!("e21".parse(), Ok(MultiVector2([0.0, 0.0, 0.0, -1.0])));
+        assert_eq!(
+            "(1e-3)e13 + (-2)".parse(),
+            Ok(MultiVector3([-2.0, 0.0, 0.0, 0.0, 0.0, 0.001, 0.0, 0.0]))
+        );
+        assert_eq!("-e11+2e2 - e2".parse(), Ok(MultiVector2([1, 0, 1, 0])));
+    }
+
+    #[test]
+    fn signatures() {
+        use crate::{CgaMultiVector, PgaMultiVector, StaMultiVector};
+
+        let pga = |s: &str| s.parse::<PgaMultiVector<f64>>().unwrap();
+        assert_eq!(pga("e11"), pga("1"));
+        assert_eq!(pga("e121"), pga("-e2"));
+        assert_eq!(pga("2 + 3e44 + e424"), pga("2"));
+        assert_eq!(pga("e1") * pga("e1"), pga("e11"));
+
+        let cga = |s: &str| s.parse::<CgaMultiVector<f64>>().unwrap();
+        assert_eq!(cga("e44 + e55"), cga("0"));
+        assert_eq!(cga("e535"), cga("e3"));
+        assert_eq!(cga("e5") * cga("e5"), cga("e55"));
+
+        let sta = |s: &str| s.parse::<StaMultiVector<f64>>().unwrap();
+        assert_eq!(sta("e11"), sta("1"));
+        assert_eq!(sta("e22 + e33"), sta("-2"));
+        assert_eq!(sta("e212"), sta("e1"));
+        assert_eq!(sta("e1") * sta("e1"), sta("e11"));
+
+        // round trip of repeated blades
+        let a = pga("e11 + e12 + e1234");
+        assert_eq!(a.to_string().parse(), Ok(a));
+    }
+
+    #[test]
+    fn round_trip() {
+        let a = MultiVector4([
+            1.5, -2.0, 0.0, 0.25, 3.0, 0.0, -7.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.125,
+        ]);
+        assert_eq!(a.to_string().parse(), Ok(a));
+    }
+
+    #[test]
+    fn errors() {
+        let error = |string: &str| {
+            let error = string.parse::<MultiVector3<f64>>().unwrap_err();
+            (error.kind, error.position, error.token)
+        };
+        use ParseErrorKind::*;
+        assert_eq!(error(""), (Empty, 0, "".to_string()));
+        assert_eq!(error("1 + e4"), (InvalidBlade, 4, "e4".to_string()));
+        assert_eq!(error("1 + 2e0"), (InvalidBlade, 5, "e0".to_string()));
+        assert_eq!(error("1 + e"), (InvalidBlade, 4, "e".to_string()));
+        assert_eq!(
+            error("1.2.3e1"),
+            (InvalidCoefficient, 0, "1.2.3".to_string())
+        );
+        assert_eq!(error("(x)e1"), (InvalidCoefficient, 0, "(x)".to_string()));
+        assert_eq!(error("2x"), (UnexpectedToken, 1, "x".to_string()));
+        assert_eq!(error("1 2"), (UnexpectedToken, 2, "2".to_string()));
+        assert_eq!(
+            error("1 +"),
+            (UnexpectedToken, 3, "end of input".to_string())
+        );
+        assert_eq!(error("1 + + 2"), (UnexpectedToken, 4, "+".to_string()));
+        assert_eq!(error("(1e1"), (UnexpectedToken, 0, "(1e1".to_string()));
+        assert_eq!(error("-e11 + e3e2"), (UnexpectedToken, 9, "e2".to_string()));
+        assert_eq!(
+            "1 + e5"
+                .parse::<MultiVector3<f64>>()
+                .unwrap_err()
+                .to_string(),
+            "invalid blade `e5` at position 4"
+        );
+    }
+}
@@ -0,0 +1,324 @@
+//! Projective geometric algebra (PGA) of 3D space, i.e. `Cl(3,0,1)`, for rigid-body geometry.
+//!
+//! Everything is a [PgaMultiVector] and uses its geometric product. The Euclidean basis vectors
+//! `e1`, `e2`, and `e3` square to `1`, the last basis vector `e4` squares to `0`. (In the common
+//! notation, `e4` is `e0`.) Geometric objects are represented by blades:
+//!
+//! * Planes `ax + by + cz + d = 0` are vectors `a e1 + b e2 + c e3 + d e4`, see
+//!   [plane](PgaMultiVector::plane).
+//! * Lines are bivectors, see [line](PgaMultiVector::line).
+//! * Points are trivectors, `x e032 + y e013 + z e021 + e123` in the common notation, see
+//!   [point](PgaMultiVector::point) and [ideal_point](PgaMultiVector::ideal_point).
+//!
+//! The [meet](PgaMultiVector::meet) intersects, the [join](PgaMultiVector::join) spans, and
+//! motors, i.e. products of [translators](PgaMultiVector::translator) and
+//! [rotors](PgaMultiVector::rotor), move objects with the [sandwich](PgaMultiVector::sandwich)
+//! product.
+//!
+//! ```
+//! # use algebraic::PgaMultiVector;
+//! let floor = PgaMultiVector::plane(0.0, 0.0, 1.0, 0.0);
+//! let ray = PgaMultiVector::line([1.0, 2.0, 3.0], [0.0, 0.0, -1.0]);
+//! let hit = floor.meet(ray);
+//! assert_eq!(hit.point_coordinates(), Some([1.0, 2.0, 0.0]));
+//!
+//! let motor = PgaMultiVector::motor([0.0, 0.0, 1.0], [0.0, 0.0, 1.0], std::f64::consts::PI);
+//! let moved = motor.sandwich(hit).point_coordinates().unwrap();
+//! assert!((moved[0] + 1.0).abs() < 1e-12 && (moved[1] + 2.0).abs() < 1e-12);
+//! assert!((moved[2] - 1.0).abs() < 1e-12);
+//! ```
+use crate::{
+    multi_vector::{grade_part, inner_product, outer_product},
+    PgaMultiVector, RingMultiVector, Scalar,
+};
+use algebraic_core::canonize;
+
+// indices of the blades
+const E1: usize = 0b0001;
+const E2: usize = 0b0010;
+const E3: usize = 0b0100;
+const E4: usize = 0b1000;
+const E12: usize = E1 | E2;
+const E13: usize = E1 | E3;
+const E23: usize = E2 | E3;
+const E14: usize = E1 | E4;
+const E24: usize = E2 | E4;
+const E34: usize = E3 | E4;
+const E123: usize = E12 | E3;
+const E124: usize = E12 | E4;
+const E134: usize = E13 | E4;
+const E234: usize = E23 | E4;
+const PSEUDOSCALAR: usize = 0b1111;
+
+// whether `first ∧ second` is the negative pseudoscalar, for complementary blades
+fn complement_negates(first: usize, second: usize) -> bool {
+    let blade = |i: usize| (0..4).filter(move |d| i >> d & 1 == 1);
+    canonize(&mut blade(first).chain(blade(second)).collect())
+}
+
+impl<T: Scalar<Real = T>> PgaMultiVector<T> {
+    fn from_coefficients(coefficients: impl IntoIterator<Item = (usize, T)>) -> Self {
+        let mut a = Self::default();
+        for (i, x) in coefficients {
+            a[i] = x;
+        }
+        a
+    }
+
+    /// The plane `ax + by + cz + d = 0`.
+    pub fn plane(a: T, b: T, c: T, d: T) -> Self {
+        Self::from_coefficients([(E1, a), (E2, b), (E3, c), (E4, d)])
+    }
+
+    /// The point at `(x, y, z)`.
+    pub fn point(x: T, y: T, z: T) -> Self {
+        Self::ideal_point(x, y, z) + Self::from_coefficients([(E123, T::one())])
+    }
+
+    /// The point at infinity in direction `(x, y, z)`, e.g. the direction of lines.
+    pub fn ideal_point(x: T, y: T, z: T) -> Self {
+        Self::from_coefficients([(E234, -x), (E134, y), (E124, -z)])
+    }
+
+    /// The line through `point` in `direction`.
+    pub fn line(point: [T; 3], direction: [T; 3]) -> Self {
+        let [x, y, z] = point;
+        let [dx, dy, dz] = direction;
+        Self::point(x, y, z).join(Self::ideal_point(dx, dy, dz))
+    }
+
+    /// The coordinates of a point, `None` for ideal points.
+    pub fn point_coordinates(self) -> Option<[T; 3]> {
+        let w = self[E123];
+        (w != T::zero()).then(|| [-self[E234] / w, self[E134] / w, -self[E124] / w])
+    }
+
+    /// Right complement, `a ∧ complement(a)` is the pseudoscalar for blades with coefficient `1`.
+    fn complement(self) -> Self {
+        Self::from_coefficients((0..Self::BASE_SIZE).map(|i| {
+            let c = PSEUDOSCALAR ^ i;
+            (
+                c,
+                if complement_negates(i, c) {
+                    -self[i]
+                } else {
+                    self[i]
+                },
+            )
+        }))
+    }
+
+    /// Left complement, undoes the [complement](Self::complement).
+    fn uncomplement(self) -> Self {
+        Self::from_coefficients((0..Self::BASE_SIZE).map(|i| {
+            let c = PSEUDOSCALAR ^ i;
+            (
+                c,
+                if complement_negates(c, i) {
+                    -self[i]
+                } else {
+                    self[i]
+                },
+            )
+        }))
+    }
+
+    /// The outer product, e.g. the intersection of planes and lines.
+    pub fn meet(self, other: Self) -> Self {
+        outer_product(self, other)
+    }
+
+    /// The regressive product, e.g. the line through two points or the plane through a point and
+    /// a line.
+    pub fn join(self, other: Self) -> Self {
+        outer_product(self.complement(), other.complement()).uncomplement()
+    }
+
+    /// The symmetric inner product, e.g. the plane through a point orthogonal to a line.
+    pub fn inner(self, other: Self) -> Self {
+        inner_product(self, other)
+    }
+
+    /// Projects `self` onto the blade `onto`, e.g. a point onto a plane or a line.
+    pub fn project(self, onto: Self) -> Self {
+        self.inner(onto) * onto / (onto * onto)[0]
+    }
+
+    /// The Euclidean magnitude, e.g. the length of the normal of a plane or the weight of a
+    /// point. Zero for ideal objects.
+    pub fn magnitude(self) -> T {
+        (self * self.reverse())[0].abs().sqrt()
+    }
+
+    /// Scaled to [magnitude](Self::magnitude) `1`.
+    pub fn normalized(self) -> Self {
+        self / self.magnitude()
+    }
+
+    /// The Euclidean distance between two points, or between a point and a plane or a line, in
+    /// either order.
+    ///
+    /// This is the magnitude of the join, which doesn't measure the distance between two planes
+    /// or two lines.
+    pub fn distance(self, other: Self) -> T {
+        self.normalized().join(other.normalized()).magnitude()
+    }
+
+    /// The angle between planes, or between lines, in `[0, π]`.
+    pub fn angle(self, other: Self) -> T {
+        // cosine and sine, both scaled by the magnitudes
+        let product = self * other.reverse();
+        grade_part(product, 2).magnitude().atan2(product[0])
+    }
+
+    /// The motor translating by `(x, y, z)`.
+    pub fn translator(x: T, y: T, z: T) -> Self {
+        let half = T::from_f64(0.5);
+        Self::from_coefficients([
+            (0, T::one()),
+            (E14, x * half),
+            (E24, y * half),
+            (E34, z * half),
+        ])
+    }
+
+    /// The motor rotating by `angle` around `axis` through the origin, counterclockwise when
+    /// looking against the axis.
+    pub fn rotor(axis: [T; 3], angle: T) -> Self {
+        let [x, y, z] = axis;
+        let length = (x * x + y * y + z * z).sqrt();
+        let half = angle * T::from_f64(0.5);
+        let sin = half.sin() / length;
+        Self::from_coefficients([
+            (0, half.cos()),
+            (E23, -x * sin),
+            (E13, y * sin),
+            (E12, -z * sin),
+        ])
+    }
+
+    /// The motor rotating by `angle` around `axis` through the origin, then translating by
+    /// `translation`.
+    pub fn motor(translation: [T; 3], axis: [T; 3], angle: T) -> Self {
+        let [x, y, z] = translation;
+        Self::translator(x, y, z) * Self::rotor(axis, angle)
+    }
+
+    /// `self * x * reverse(self)`, moves `x` by the motor `self`.
+    pub fn sandwich(self, x: Self) -> Self {
+        self * x * self.reverse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    type Pga = PgaMultiVector<f64>;
+
+    fn close(a: [f64; 3], b: [f64; 3]) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-12)
+    }
+
+    fn coordinates(p: Pga) -> [f64; 3] {
+        p.point_coordinates().unwrap()
+    }
+
+    #[test]
+    fn incidence() {
+        let p = Pga::point(1.0, 2.0, 3.0);
+        let q = Pga::point(-1.0, 0.0, 2.0);
+        let r = Pga::point(0.0, 4.0, -1.0);
+        let line = p.join(q);
+        let plane = line.join(r);
+        for point in [p, q, r] {
+            assert!(plane.meet(point).into_iter().all(|x| x.abs() < 1e-12));
+        }
+        for point in [p, q] {
+            assert!(point.join(line).into_iter().all(|x| x.abs() < 1e-12));
+        }
+        assert!(line.meet(p).into_iter().all(|x| x.abs() < 1e-12));
+
+        // three planes meet in a point
+        let x = Pga::plane(1.0, 0.0, 0.0, -1.0);
+        let y = Pga::plane(0.0, 1.0, 0.0, -2.0);
+        let z = Pga::plane(0.0, 0.0, 1.0, -3.0);
+        assert!(close(coordinates(x.meet(y).meet(z)), [1.0, 2.0, 3.0]));
+
+        // parallel planes meet at infinity
+        let parallel = Pga::plane(1.0, 0.0, 0.0, 5.0);
+        assert_eq!(x.meet(parallel).meet(y).point_coordinates(), None);
+    }
+
+    #[test]
+    fn metric() {
+        let p = Pga::point(1.0, 2.0, 3.0);
+        let q = Pga::point(4.0, 6.0, 3.0);
+        assert!((p.distance(q) - 5.0).abs() < 1e-12);
+        assert!(((p * 2.0).distance(q) - 5.0).abs() < 1e-12);
+
+        let plane = Pga::plane(0.0, 0.0, 2.0, -2.0);
+        assert!((p.distance(plane) - 2.0).abs() < 1e-12);
+        assert!((plane.distance(p) - 2.0).abs() < 1e-12);
+        assert!(Pga::point(5.0, -1.0, 1.0).distance(plane) < 1e-12);
+
+        let z_axis = Pga::line([0.0, 0.0, 0.0], [0.0, 0.0, 1.0]);
+        assert!((q.distance(z_axis) - 52f64.sqrt()).abs() < 1e-12);
+        assert!((z_axis.distance(q) - 52f64.sqrt()).abs() < 1e-12);
+        assert!(Pga::point(0.0, 0.0, -7.0).distance(z_axis) < 1e-12);
+
+        let diagonal = Pga::plane(1.0, 1.0, 0.0, 0.0);
+        assert!((plane.angle(diagonal) - FRAC_PI_2).abs() < 1e-12);
+        assert!((Pga::plane(1.0, 0.0, 0.0, 3.0).angle(diagonal) - FRAC_PI_4).abs() < 1e-12);
+        let line = Pga::line([1.0, 1.0, 1.0], [1.0, 0.0, 1.0]);
+        assert!((line.angle(z_axis) - FRAC_PI_4).abs() < 1e-12);
+        assert!((line.angle(line * -1.0) - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn projection() {
+        let p = Pga::point(1.0, 2.0, 3.0);
+        let floor = Pga::plane(0.0, 0.0, 1.0, 0.0);
+        assert!(close(coordinates(p.project(floor)), [1.0, 2.0, 0.0]));
+
+        let line = Pga::line([0.0, 0.0, 0.0], [1.0, 1.0, 0.0]);
+        assert!(close(coordinates(p.project(line)), [1.5, 1.5, 0.0]));
+
+        // a line onto a plane
+        let projected = Pga::line([0.0, 0.0, 1.0], [1.0, 0.0, 1.0]).project(floor);
+        let expected = Pga::line([-1.0, 0.0, 0.0], [1.0, 0.0, 0.0]);
+        assert!(projected.normalized().distance(expected) < 1e-12);
+        assert!(projected.angle(expected) < 1e-6);
+    }
+
+    #[test]
+    fn motors() {
+        let p = Pga::point(1.0, 2.0, 3.0);
+        let translator = Pga::translator(1.0, -1.0, 2.0);
+        assert!(close(coordinates(translator.sandwich(p)), [2.0, 1.0, 5.0]));
+
+        let rotor = Pga::rotor([0.0, 0.0, 2.0], FRAC_PI_2);
+        assert!(close(coordinates(rotor.sandwich(p)), [-2.0, 1.0, 3.0]));
+        let rotor = Pga::rotor([1.0, 0.0, 0.0], FRAC_PI_2);
+        assert!(close(coordinates(rotor.sandwich(p)), [1.0, -3.0, 2.0]));
+
+        // rotate, then translate
+        let motor = Pga::motor([1.0, -1.0, 2.0], [0.0, 1.0, 0.0], PI);
+        assert!(close(coordinates(motor.sandwich(p)), [0.0, 1.0, -1.0]));
+
+        // motors preserve incidence and distances
+        let q = Pga::point(-2.0, 0.5, 1.0);
+        let line = p.join(q);
+        let moved = motor.sandwich(line);
+        assert!(moved
+            .join(motor.sandwich(q))
+            .into_iter()
+            .all(|x| x.abs() < 1e-12));
+        assert!((motor.sandwich(p).distance(motor.sandwich(q)) - p.distance(q)).abs() < 1e-12);
+        let plane = Pga::plane(1.0, 2.0, 3.0, 4.0);
+        assert!(
+            (motor.sandwich(p).distance(motor.sandwich(plane)) - p.distance(plane)).abs() < 1e-12
+        );
+    }
+}
@@ -0,0 +1,391 @@
+//! This module contains the [Rotor] trait for interpolating rotations in 2, 3, and 4 dimensions,
+//! and converting them to and from rotation matrices.
+//!
+//! Rotors are the normalized elements of the even subalgebra, e.g. `exp(θ/2 e12)`, acting on
+//! vectors with `rotor * v * reverse(rotor)`. `rotor` and `-rotor` are the same rotation, so the
+//! interpolations take the shorter way around. Everything is built on the
+//! [exponential](crate::MultiVector::exp), the rotor [logarithm](Rotor::log), and the
+//! [normalization](Rotor::normalized).
+//!
+//! ```
+//! # use algebraic::{MultiVector, MultiVector3, Rotor};
+//! let start = MultiVector3::base_element(0);
+//! let end = (MultiVector3::base_element(0b011) * 0.8).exp();
+//! let quarter = (MultiVector3::base_element(0b011) * 0.2).exp();
+//! let interpolated = start.slerp(end, 0.25);
+//! assert!((interpolated - quarter).norm() < 1e-12);
+//! ```
+use crate::{
+    multi_vector::grade_part, MultiVector, MultiVector2, MultiVector3, MultiVector4,
+    RingMultiVector, Scalar,
+};
+use std::cmp::Ordering;
+
+/// Interpolation of rotors, i.e. normalized even multi vectors, for [MultiVector2],
+/// [MultiVector3], and [MultiVector4].
+pub trait Rotor<T>: MultiVector<T>
+where
+    T: Scalar<Real = T> + PartialOrd,
+{
+    /// `[[T; N]; N]` in `N` dimensions.
+    type Matrix;
+
+    /// The orthogonal matrix rotating like `self`: `matrix[i][j]` is the `e(i+1)` coefficient of
+    /// the rotated `e(j+1)`, i.e. the columns are the rotated basis vectors.
+    fn to_rotation_matrix(self) -> Self::Matrix;
+
+    /// The rotor of an orthogonal matrix with determinant `1`, see
+    /// [to_rotation_matrix](Rotor::to_rotation_matrix). Of the two rotors `±R`, the one with a
+    /// positive coefficient at its largest blade.
+    ///
+    /// For every even blade `B`, `Σ f(eA) B eA⁻¹` over all blades `eA` is `R` scaled by
+    /// `2^N <reverse(R) B>₀`, where `f(eA) = R eA reverse(R)` is the product of the columns. Using
+    /// the largest of these avoids the cancellation that e.g. `1 + Σ f(ei) ei⁻¹` suffers from near
+    /// rotations by `π`.
+    fn from_rotation_matrix(matrix: Self::Matrix) -> Self;
+
+    /// The bivector `B` with `B.exp() == self`, i.e. half the rotation angle times the unit
+    /// rotation plane, angles in `[0, π]`.
+    ///
+    /// In 4 dimensions, rotations generally happen in two orthogonal planes at once. The rotor is
+    /// split into its self-dual and anti-self-dual parts with the central idempotents
+    /// `(1 ± e1234) / 2`, which behave like simple rotors on their own.
+    fn log(self) -> Self {
+        if Self::ALGEBRA_DIMENSION == 4 {
+            let half = T::from_f64(0.5);
+            let pseudoscalar = Self::base_element(Self::BASE_SIZE - 1);
+            let plus = (Self::base_element(0) + pseudoscalar) * half;
+            let minus = (Self::base_element(0) - pseudoscalar) * half;
+            simple_log(self * plus, plus) + simple_log(self * minus, minus)
+        } else {
+            simple_log(self, Self::base_element(0))
+        }
+    }
+
+    /// Scaled to `self * reverse(self) == 1`, the [norm](MultiVector::norm) of even multi
+    /// vectors.
+    fn normalized(self) -> Self {
+        self / self.norm()
+    }
+
+    /// Normalized linear interpolation, from `self` at `t = 0` to `other` at `t = 1`. Cheaper
+    /// than [slerp](Rotor::slerp), but faster in the middle.
+    fn nlerp(self, other: Self, t: T) -> Self {
+        let other = aligned(self, other);
+        (self * (T::one() - t) + other * t).normalized()
+    }
+
+    /// Spherical linear interpolation, from `self` at `t = 0` to `other` at `t = 1` with
+    /// constant angular velocity.
+    fn slerp(self, other: Self, t: T) -> Self {
+        let relative = aligned(Self::base_element(0), self.reverse() * other);
+        self * (relative.log() * t).exp()
+    }
+
+    /// The weighted average of `rotors` in log space, i.e. the rotor from which the weighted
+    /// logarithms of the others sum to zero. `None` if there are no rotors, or the weights sum to
+    /// zero.
+    ///
+    /// Starts with the first rotor, and moves by the average logarithm until it doesn't change
+    /// anymore.
+    fn blend(rotors: &[(Self, T)]) -> Option<Self> {
+        let total = rotors.iter().fold(T::zero(), |sum, &(_, w)| sum + w);
+        let &(mut mean, _) = rotors.first()?;
+        if total == T::zero() {
+            return None;
+        }
+        for _ in 0..32 {
+            let step = rotors.iter().fold(Self::default(), |sum, &(rotor, w)| {
+                let relative = aligned(Self::base_element(0), mean.reverse() * rotor);
+                sum + relative.log() * (w / total)
+            });
+            let next = (mean * step.exp()).normalized();
+            if next.into_iter().zip(mean).all(|(n, m)| n == m) {
+                break;
+            }
+            mean = next;
+        }
+        Some(mean)
+    }
+}
+
+macro_rules! impl_rotor {
+    ($($name:ident: $dimension:literal),*) => {
+        $(
+            impl<T: Scalar<Real = T> + PartialOrd> Rotor<T> for $name<T> {
+                type Matrix = [[T; $dimension]; $dimension];
+
+                fn to_rotation_matrix(self) -> Self::Matrix {
+                    std::array::from_fn(|i| {
+                        std::array::from_fn(|j| {
+                            (self * Self::base_element(1 << j) * self.reverse())[1 << i]
+                        })
+                    })
+                }
+
+                fn from_rotation_matrix(matrix: Self::Matrix) -> Self {
+                    let columns: [Self; $dimension] = std::array::from_fn(|j| {
+                        let mut column = Self::default();
+                        for (i, row) in matrix.iter().enumerate() {
+                            column[1 << i] = row[j];
+                        }
+                        column
+                    });
+                    from_columns(&columns)
+                }
+            }
+        )*
+    };
+}
+
+impl_rotor!(MultiVector2: 2, MultiVector3: 3, MultiVector4: 4);
+
+// the rotor rotating the basis vectors to `columns`, see `Rotor::from_rotation_matrix`
+fn from_columns<T, M>(columns: &[M]) -> M
+where
+    T: Scalar<Real = T> + PartialOrd,
+    M: MultiVector<T>,
+{
+    // `f(eA)` and `eA⁻¹` for all blades, the `eA` are products of the basis vectors
+    let images: Vec<_> = (0..M::BASE_SIZE)
+        .map(|a| {
+            (0..M::ALGEBRA_DIMENSION)
+                .filter(|i| a >> i & 1 == 1)
+                .fold(M::base_element(0), |product, i| product * columns[i])
+        })
+        .collect();
+    let inverses: Vec<_> = (0..M::BASE_SIZE)
+        .map(|a| {
+            let blade = M::base_element(a);
+            blade.reverse() / (blade * blade.reverse())[0]
+        })
+        .collect();
+
+    let (norm, b, scaled) = (0..M::BASE_SIZE)
+        .filter(|b| b.count_ones() % 2 == 0)
+        .map(|b| {
+            let blade = M::base_element(b);
+            let scaled = images
+                .iter()
+                .zip(&inverses)
+                .fold(M::default(), |sum, (&image, &inverse)| {
+                    sum + image * blade * inverse
+                });
+            (scaled.norm(), b, scaled)
+        })
+        .max_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(Ordering::Equal))
+        .expect("the scalar is even");
+    if scaled[b] < T::zero() {
+        scaled / -norm
+    } else {
+        scaled / norm
+    }
+}
+
+// `rotor` or `-rotor`, whichever is closer to `reference`
+fn aligned<T, M>(reference: M, rotor: M) -> M
+where
+    T: Scalar<Real = T> + PartialOrd,
+    M: MultiVector<T>,
+{
+    if (reference.reverse() * rotor)[0] < T::zero() {
+        rotor * -T::one()
+    } else {
+        rotor
+    }
+}
+
+// the logarithm of `c unit + B` in the ideal of the idempotent `unit`, with `B² = -n² unit`
+fn simple_log<T, M>(a: M, unit: M) -> M
+where
+    T: Scalar<Real = T> + PartialOrd,
+    M: MultiVector<T>,
+{
+    let c = a[0] / unit[0];
+    let mut bivector = grade_part(a, 2);
+    let mut n = (-(bivector * bivector)[0] / unit[0]).sqrt();
+    let angle = n.atan2(c);
+    if n == T::zero() {
+        if c >= T::zero() {
+            return M::default();
+        }
+        // the rotor `-unit` rotates by `2π` in any plane, e.g. `e12`
+        bivector = grade_part(M::base_element(0b11) * unit, 2);
+        n = (-(bivector * bivector)[0] / unit[0]).sqrt();
+    }
+    bivector * (angle / n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn close<M: MultiVector<f64>>(a: M, b: M) -> bool {
+        (a - b).norm() < 1e-12
+    }
+
+    // `exp(a e12 + b e34)`, rotating by `2a` in `e12` and `2b` in `e34`
+    fn double_rotation(a: f64, b: f64) -> MultiVector4<f64> {
+        (MultiVector4::base_element(0b0011) * a + MultiVector4::base_element(0b1100) * b).exp()
+    }
+
+    #[test]
+    fn log() {
+        let rotor = (MultiVector2::base_element(0b11) * 1.2).exp();
+        assert!(close(rotor.log(), MultiVector2::base_element(0b11) * 1.2));
+        let bivector = MultiVector3([0.0, 0.0, 0.0, 0.3, 0.0, -0.4, 1.2, 0.0]);
+        assert!(close(bivector.exp().log(), bivector));
+        let bivector = MultiVector4::base_element(0b0011) * 0.5
+            + MultiVector4::base_element(0b1100) * -1.1
+            + MultiVector4::base_element(0b0110) * 0.7;
+        assert!(close(bivector.exp().log(), bivector));
+        // isoclinic, one of the parts is the identity
+        let isoclinic =
+            MultiVector4::base_element(0b0011) * 0.5 + MultiVector4::base_element(0b1100) * 0.5;
+        assert!(close(double_rotation(0.5, 0.5).log(), isoclinic));
+
+        // rotations by `2π`
+        let minus_one = MultiVector3::base_element(0) * -1.0;
+        assert!(close(minus_one.log().exp(), minus_one));
+        let pseudoscalar = MultiVector4::base_element(0b1111);
+        assert!(close(pseudoscalar.log().exp(), pseudoscalar));
+        assert_eq!(
+            MultiVector4::<f64>::base_element(0).log(),
+            MultiVector4::default()
+        );
+    }
+
+    #[test]
+    fn constant_angular_velocity() {
+        let start = (MultiVector3([0.0, 0.0, 0.0, 0.1, 0.0, 0.5, -0.2, 0.0])).exp();
+        let end = (MultiVector3([0.0, 0.0, 0.0, -0.6, 0.0, 0.2, 0.9, 0.0])).exp();
+        let step = (start.reverse() * start.slerp(end, 0.1)).log();
+        for i in 0..10 {
+            let (a, b) = (i as f64 / 10.0, (i + 1) as f64 / 10.0);
+            let relative = start.slerp(end, a).reverse() * start.slerp(end, b);
+            assert!(close(relative.log(), step));
+        }
+        assert!(close(start.slerp(end, 1.0), end));
+
+        // both planes of a double rotation at their own constant speed
+        let end = double_rotation(0.4, -1.2);
+        for t in [0.0, 0.3, 0.5, 1.0] {
+            let start = MultiVector4::base_element(0);
+            assert!(close(
+                start.slerp(end, t),
+                double_rotation(0.4 * t, -1.2 * t)
+            ));
+        }
+    }
+
+    #[test]
+    fn shortest_path() {
+        let start = MultiVector2::base_element(0);
+        // `end` rotates by `3π/2`, `-end` by `-π/2`
+        let end = (MultiVector2::base_element(0b11) * 0.75 * PI).exp();
+        let halfway = (MultiVector2::base_element(0b11) * -0.125 * PI).exp();
+        assert!(close(start.slerp(end, 0.5), halfway));
+        assert!(close(start.slerp(end * -1.0, 0.5), halfway));
+        assert!(close(start.nlerp(end, 0.5), halfway));
+        assert!(close(start.nlerp(end * -1.0, 0.5), halfway));
+        assert!(close(start.slerp(end, 1.0), end * -1.0));
+
+        let start = MultiVector3::base_element(0);
+        let end = MultiVector3([-0.6, 0.0, 0.0, 0.0, 0.0, 0.0, 0.8, 0.0]);
+        assert!(close(start.slerp(end, 0.3), start.slerp(end * -1.0, 0.3)));
+        assert!(start.slerp(end, 0.3)[0] > 0.9);
+    }
+
+    #[test]
+    fn rotation_matrices() {
+        // `exp(φ e12)` rotates `e1` towards `e2` by `2φ`
+        let (cos, sin) = (0.6f64.cos(), 0.6f64.sin());
+        let matrix = (MultiVector2::base_element(0b11) * 0.3)
+            .exp()
+            .to_rotation_matrix();
+        assert!(matrix
+            .iter()
+            .flatten()
+            .zip([cos, -sin, sin, cos])
+            .all(|(a, b)| (a - b).abs() < 1e-12));
+
+        let rotor = MultiVector3([0.1, 0.0, 0.0, 0.5, 0.0, -0.7, 0.3, 0.0]).normalized();
+        let matrix = rotor.to_rotation_matrix();
+        let v = MultiVector3([0.0, 1.0, -2.0, 0.0, 0.5, 0.0, 0.0, 0.0]);
+        let rotated = rotor * v * rotor.reverse();
+        for (i, row) in matrix.iter().enumerate() {
+            let product = row
+                .iter()
+                .zip([v[1], v[2], v[4]])
+                .map(|(a, b)| a * b)
+                .sum::<f64>();
+            assert!((product - rotated[1 << i]).abs() < 1e-12);
+            // orthogonal
+            for (j, other) in matrix.iter().enumerate() {
+                let dot = row.iter().zip(other).map(|(a, b)| a * b).sum::<f64>();
+                assert!((dot - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn half_turns() {
+        // by `π` around `e3`
+        let matrix = [[-1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]];
+        let rotor = MultiVector3::from_rotation_matrix(matrix);
+        assert!(close(rotor, MultiVector3::base_element(0b011)));
+
+        // nearly by `π`, the scalar part is tiny
+        let plane = MultiVector3([0.0, 0.0, 0.0, 0.6, 0.0, 0.0, -0.8, 0.0]);
+        for angle in [PI, PI - 1e-9, PI - 1e-6, PI + 1e-6] {
+            let rotor = (plane * (angle / 2.0)).exp();
+            let extracted = MultiVector3::from_rotation_matrix(rotor.to_rotation_matrix());
+            assert!(close(extracted, rotor) || close(extracted, rotor * -1.0));
+        }
+
+        // by `π` in both planes, i.e. the point reflection
+        let mut minus_identity = [[0.0; 4]; 4];
+        for (i, row) in minus_identity.iter_mut().enumerate() {
+            row[i] = -1.0;
+        }
+        let rotor = MultiVector4::from_rotation_matrix(minus_identity);
+        assert!(close(rotor, MultiVector4::base_element(0b1111)));
+        let rotor = double_rotation(PI / 2.0 - 1e-7, PI / 2.0);
+        let extracted = MultiVector4::from_rotation_matrix(rotor.to_rotation_matrix());
+        assert!(close(extracted, rotor) || close(extracted, rotor * -1.0));
+    }
+
+    #[test]
+    fn blend() {
+        let a = (MultiVector3::base_element(0b011) * 0.2).exp();
+        let b = (MultiVector3::base_element(0b011) * 1.0).exp();
+        let blended = Rotor::blend(&[(a, 3.0), (b, 1.0)]).unwrap();
+        assert!(close(
+            blended,
+            (MultiVector3::base_element(0b011) * 0.4).exp()
+        ));
+        assert!(close(
+            Rotor::blend(&[(a, 1.0), (b, 1.0)]).unwrap(),
+            a.slerp(b, 0.5)
+        ));
+        // the sign of the rotors doesn't matter
+        assert!(close(
+            Rotor::blend(&[(a, 1.0), (b * -1.0, 1.0)]).unwrap(),
+            a.slerp(b, 0.5)
+        ));
+
+        // symmetric around the identity
+        let rotors = [
+            (MultiVector3::base_element(0b011) * 0.3).exp(),
+            (MultiVector3::base_element(0b011) * -0.3).exp(),
+            (MultiVector3::base_element(0b110) * 0.3).exp(),
+            (MultiVector3::base_element(0b110) * -0.3).exp(),
+        ];
+        let blended = Rotor::blend(&rotors.map(|r| (r, 0.25))).unwrap();
+        assert!(close(blended, MultiVector3::base_element(0)));
+
+        assert_eq!(MultiVector3::<f64>::blend(&[]), None);
+        assert_eq!(Rotor::blend(&[(a, 1.0), (b, -1.0)]), None);
+    }
+}
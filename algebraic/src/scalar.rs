@@ -0,0 +1,278 @@
+//! This module contains the [Ring] and [Scalar] traits for the coefficients of multi vectors.
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// Coefficients of a [RingMultiVector](crate::RingMultiVector): anything that can be added,
+/// subtracted, and multiplied, e.g. integers or [ModP](crate::ModP).
+///
+/// [Neg] is required as well, which excludes the unsigned integers on purpose: the products
+/// subtract, so they would overflow. Use [Wrapping](std::num::Wrapping) or [ModP](crate::ModP)
+/// for arithmetic modulo a power of two or any other modulus.
+///
+/// ```
+/// # use algebraic::MultiVector2;
+/// # use std::num::Wrapping;
+/// let a = MultiVector2([1u8, 2, 3, 4].map(Wrapping));
+/// // 1 - 4 - 9 - 16 modulo 256
+/// assert_eq!((a * a)[0], Wrapping(228));
+/// ```
+///
+/// This is just an alias, it is implemented for every type with the required traits.
+pub trait Ring:
+    Copy
+    + Default
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+}
+
+impl<T> Ring for T where
+    T: Copy
+        + Default
+        + Debug
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Neg<Output = T>
+{
+}
+
+/// Real or complex numbers as far as [MultiVector](crate::MultiVector) is concerned: field
+/// arithmetic, absolute values, and the usual functions for norms and exponentials.
+///
+/// Implemented for [f32], [f64], [Dual](crate::Dual), and [Symbol](crate::Symbol). With the
+/// `num-complex` feature, also for [Complex](https://docs.rs/num-complex/latest/num_complex/struct.Complex.html).
+/// With the `num-traits` feature, any [num_traits::Float](https://docs.rs/num-traits/latest/num_traits/float/trait.Float.html)
+/// is a [Scalar] through the [Float] wrapper.
+pub trait Scalar: Ring + PartialEq + Div<Output = Self> {
+    /// The type of absolute values, `Self` for real numbers.
+    type Real: Scalar<Real = Self::Real> + PartialOrd;
+
+    fn zero() -> Self;
+    fn one() -> Self;
+    /// Nearest representable value, for constants.
+    fn from_f64(value: f64) -> Self;
+
+    fn abs(self) -> Self::Real;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($float:ty),*) => {
+        $(
+            impl Scalar for $float {
+                type Real = Self;
+
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn one() -> Self {
+                    1.0
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $float
+                }
+
+                fn abs(self) -> Self {
+                    self.abs()
+                }
+
+                fn sqrt(self) -> Self {
+                    self.sqrt()
+                }
+
+                fn exp(self) -> Self {
+                    self.exp()
+                }
+
+                fn ln(self) -> Self {
+                    self.ln()
+                }
+
+                fn sin(self) -> Self {
+                    self.sin()
+                }
+
+                fn cos(self) -> Self {
+                    self.cos()
+                }
+
+                fn acos(self) -> Self {
+                    self.acos()
+                }
+
+                fn atan2(self, other: Self) -> Self {
+                    self.atan2(other)
+                }
+
+                fn sinh(self) -> Self {
+                    self.sinh()
+                }
+
+                fn cosh(self) -> Self {
+                    self.cosh()
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(f32, f64);
+
+/// Any [num_traits::Float](https://docs.rs/num-traits/latest/num_traits/float/trait.Float.html)
+/// as a [Scalar], e.g. a half or software float.
+///
+/// This is a wrapper rather than a blanket implementation, which would conflict with the
+/// implementations for other scalars like `Complex`.
+///
+/// ```
+/// # use algebraic::{Float, MultiVector, MultiVector2};
+/// let a = MultiVector2([3.0, 0.0, 0.0, 4.0].map(Float));
+/// assert_eq!(a.norm(), Float(5.0));
+/// ```
+#[cfg(feature = "num-traits")]
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Float<T>(pub T);
+
+#[cfg(feature = "num-traits")]
+macro_rules! impl_float_operator {
+    ($($operator:ident $function:ident),*) => {
+        $(
+            impl<T: num_traits::Float> std::ops::$operator for Float<T> {
+                type Output = Self;
+
+                fn $function(self, rhs: Self) -> Self {
+                    Self(self.0.$function(rhs.0))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "num-traits")]
+impl_float_operator!(Add add, Sub sub, Mul mul, Div div);
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::Float> Neg for Float<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: Debug> Debug for Float<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: std::fmt::Display> std::fmt::Display for Float<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::Float + Default + Debug> Scalar for Float<T> {
+    type Real = Self;
+
+    fn zero() -> Self {
+        Self(T::zero())
+    }
+
+    fn one() -> Self {
+        Self(T::one())
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Self(T::from(value).unwrap())
+    }
+
+    fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    fn sqrt(self) -> Self {
+        Self(self.0.sqrt())
+    }
+
+    fn exp(self) -> Self {
+        Self(self.0.exp())
+    }
+
+    fn ln(self) -> Self {
+        Self(self.0.ln())
+    }
+
+    fn sin(self) -> Self {
+        Self(self.0.sin())
+    }
+
+    fn cos(self) -> Self {
+        Self(self.0.cos())
+    }
+
+    fn acos(self) -> Self {
+        Self(self.0.acos())
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        Self(self.0.atan2(other.0))
+    }
+
+    fn sinh(self) -> Self {
+        Self(self.0.sinh())
+    }
+
+    fn cosh(self) -> Self {
+        Self(self.0.cosh())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypotenuse<T: Scalar>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(hypotenuse(3.0f32, 4.0), 5.0);
+        assert_eq!(hypotenuse(3.0f64, 4.0), 5.0);
+        assert_eq!(<f32 as Scalar>::from_f64(0.5), 0.5);
+        assert_eq!(<f64 as Scalar>::zero() + <f64 as Scalar>::one(), 1.0);
+        assert_eq!(Scalar::atan2(1.0f64, 1.0), std::f64::consts::FRAC_PI_4);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits_floats() {
+        assert_eq!(hypotenuse(Float(3.0f32), Float(4.0)), Float(5.0));
+        assert_eq!(Float::<f64>::from_f64(0.5) - Float::one(), Float(-0.5));
+        assert_eq!(
+            Scalar::atan2(Float(1.0f64), Float(1.0)).0,
+            std::f64::consts::FRAC_PI_4
+        );
+        assert_eq!(format!("{:?} {}", Float(0.5f32), Float(2.5f64)), "0.5 2.5");
+    }
+}
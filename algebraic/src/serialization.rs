@@ -0,0 +1,207 @@
+//! [serde] support. (Only with the `serde` feature.)
+//!
+//! By default, multi vectors are serialized as a compact array of all coefficients (wrt. the
+//! generated base). Deserialization fails unless the array has exactly `BASE_SIZE` elements.
+//!
+//! Alternatively, [blade_map] serializes a map from blade names to the non-zero coefficients,
+//! e.g. `{"1": 2.0, "e12": 0.5}`:
+//!
+//! ```
+//! # use algebraic::MultiVector3;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Pose {
+//!     #[serde(with = "algebraic::serialization::blade_map")]
+//!     motor: MultiVector3<f64>,
+//! }
+//! ```
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt;
+
+pub(crate) fn serialize_array<T: Serialize, S: Serializer>(
+    coefficients: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(coefficients.len())?;
+    for c in coefficients {
+        tuple.serialize_element(c)?;
+    }
+    tuple.end()
+}
+
+struct ArrayVisitor<'a, T>(&'a mut [T]);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ArrayVisitor<'_, T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of {} coefficients", self.0.len())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let length = self.0.len();
+        for i in 0..length {
+            self.0[i] = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<T>()?.is_some() {
+            let mut surplus = 1;
+            while seq.next_element::<T>()?.is_some() {
+                surplus += 1;
+            }
+            return Err(A::Error::invalid_length(length + surplus, &self));
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn deserialize_array<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+    coefficients: &mut [T],
+) -> Result<(), D::Error> {
+    deserializer.deserialize_tuple(coefficients.len(), ArrayVisitor(coefficients))
+}
+
+/// Serializes the non-zero coefficients as a map from blade names, use with
+/// `#[serde(with = "algebraic::serialization::blade_map")]`.
+///
+/// The names are those of [index_blade_name](crate::display::index_blade_name), e.g. `1` for the
+/// scalar and `e12`. Deserialization rejects other names and duplicates, missing blades are zero.
+pub mod blade_map {
+    use crate::display::index_blade_name;
+    use serde::{
+        de::{Error, MapAccess, Visitor},
+        ser::SerializeMap,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+    use std::{fmt, marker::PhantomData};
+
+    pub fn serialize<M, T, S>(multi_vector: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        M: AsRef<[T]>,
+        T: Serialize + Default + PartialEq,
+        S: Serializer,
+    {
+        let zero = T::default();
+        let coefficients = multi_vector.as_ref();
+        let mut map =
+            serializer.serialize_map(Some(coefficients.iter().filter(|c| **c != zero).count()))?;
+        for (i, c) in coefficients.iter().enumerate() {
+            if *c != zero {
+                map.serialize_entry(&index_blade_name(i), c)?;
+            }
+        }
+        map.end()
+    }
+
+    struct MapVisitor<M, T>(PhantomData<(M, T)>);
+
+    impl<'de, M, T> Visitor<'de> for MapVisitor<M, T>
+    where
+        M: Default + AsMut<[T]>,
+        T: Deserialize<'de>,
+    {
+        type Value = M;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a map from blade names to coefficients")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<M, A::Error> {
+            let mut multi_vector = M::default();
+            let coefficients = multi_vector.as_mut();
+            let names = (0..coefficients.len())
+                .map(index_blade_name)
+                .collect::<Vec<_>>();
+            let mut seen = vec![false; coefficients.len()];
+            while let Some(name) = map.next_key::<String>()? {
+                let index = names
+                    .iter()
+                    .position(|n| *n == name)
+                    .ok_or_else(|| A::Error::custom(format!("unknown blade `{name}`")))?;
+                if seen[index] {
+                    return Err(A::Error::custom(format!("duplicate blade `{name}`")));
+                }
+                seen[index] = true;
+                coefficients[index] = map.next_value()?;
+            }
+            Ok(multi_vector)
+        }
+    }
+
+    pub fn deserialize<'de, M, T, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: Default + AsMut<[T]>,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiVector2, MultiVector3, MultiVector6};
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Pose {
+        #[serde(with = "blade_map")]
+        motor: MultiVector3<f64>,
+    }
+
+    #[test]
+    fn array() {
+        let a = MultiVector2([1.0, 2.0, 0.0, -0.5]);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, "[1.0,2.0,0.0,-0.5]");
+        assert_eq!(serde_json::from_str::<MultiVector2<f64>>(&json).unwrap(), a);
+
+        let b = MultiVector6(std::array::from_fn(|i| i as i32));
+        let json = serde_json::to_string(&b).unwrap();
+        assert_eq!(serde_json::from_str::<MultiVector6<i32>>(&json).unwrap(), b);
+    }
+
+    #[test]
+    fn array_length() {
+        let error = |json| {
+            serde_json::from_str::<MultiVector2<f64>>(json)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("[1.0, 2.0, 3.0]"),
+            "invalid length 3, expected an array of 4 coefficients at line 1 column 15"
+        );
+        assert_eq!(
+            error("[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]"),
+            "invalid length 6, expected an array of 4 coefficients at line 1 column 30"
+        );
+    }
+
+    #[test]
+    fn blades() {
+        let pose = Pose {
+            motor: MultiVector3([2.0, 0.0, 0.0, 0.5, 0.0, 0.0, -1.0, 0.0]),
+        };
+        let json = serde_json::to_string(&pose).unwrap();
+        assert_eq!(json, r#"{"motor":{"1":2.0,"e12":0.5,"e23":-1.0}}"#);
+        assert_eq!(
+            serde_json::from_str::<Pose>(&json).unwrap().motor,
+            pose.motor
+        );
+
+        for (json, message) in [
+            (r#"{"motor":{"e4":1.0}}"#, "unknown blade `e4`"),
+            (r#"{"motor":{"e21":1.0}}"#, "unknown blade `e21`"),
+            (r#"{"motor":{"e1":1.0,"e1":2.0}}"#, "duplicate blade `e1`"),
+        ] {
+            let error = serde_json::from_str::<Pose>(json).unwrap_err().to_string();
+            assert!(error.starts_with(message), "{error}");
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Spacetime algebra (STA), i.e. `Cl(1,3)`, for special relativity and electromagnetism.
+//!
+//! Everything is a [StaMultiVector] and uses its geometric product. The basis vector `e1` is the
+//! timelike `γ0` (squaring to `1`), `e2`, `e3`, and `e4` are the spacelike `γ1`, `γ2`, and `γ3`
+//! (squaring to `-1`). Units have `c = 1`.
+//!
+//! The [split](StaMultiVector::split) wrt. the observer `γ0` turns an
+//! [event](StaMultiVector::event) `x` into its time and relative position, `x γ0 = t + x`, with
+//! the relative vectors `σi = γi γ0`. Boosts and rotations are rotors, the exponentials of
+//! bivectors, that act with the [sandwich](StaMultiVector::sandwich) product. Electromagnetic
+//! fields are the [Faraday](StaMultiVector::faraday) bivector `F = E + I B`, with the
+//! pseudoscalar `I = γ0123`, which gives the [Lorentz force](StaMultiVector::lorentz_force)
+//! `q F·v`.
+//!
+//! ```
+//! # use algebraic::StaMultiVector;
+//! // boosts along the same direction add their rapidities
+//! let a = StaMultiVector::boost([1.0, 0.0, 0.0], 0.5f64.atanh());
+//! let b = StaMultiVector::boost([1.0, 0.0, 0.0], 0.6f64.atanh());
+//! let [v, _, _] = (b * a).sandwich(StaMultiVector::event(1.0, [0.0; 3])).velocity();
+//! assert!((v - 1.1 / 1.3).abs() < 1e-12);
+//! ```
+use crate::{multi_vector::inner_product, MultiVector, RingMultiVector, Scalar, StaMultiVector};
+
+// indices of the basis vectors
+const GAMMA: [usize; 4] = [0b0001, 0b0010, 0b0100, 0b1000];
+const PSEUDOSCALAR: usize = 0b1111;
+
+impl<T: Scalar<Real = T>> StaMultiVector<T> {
+    // `σi = γi γ0`, i.e. `i`th relative basis vector
+    fn sigma(i: usize) -> Self {
+        Self::base_element(GAMMA[i + 1]) * Self::base_element(GAMMA[0])
+    }
+
+    // the relative vector `x1 σ1 + x2 σ2 + x3 σ3`
+    fn relative(x: [T; 3]) -> Self {
+        x.into_iter()
+            .enumerate()
+            .fold(Self::default(), |sum, (i, x)| sum + Self::sigma(i) * x)
+    }
+
+    /// The event (spacetime vector) `t γ0 + x γ1 + y γ2 + z γ3`.
+    pub fn event(t: T, [x, y, z]: [T; 3]) -> Self {
+        let mut a = Self::default();
+        for (i, x) in GAMMA.into_iter().zip([t, x, y, z]) {
+            a[i] = x;
+        }
+        a
+    }
+
+    /// The unit timelike vector of a particle moving with the relative `velocity`, i.e.
+    /// `γ (γ0 + v)`.
+    pub fn four_velocity(velocity: [T; 3]) -> Self {
+        let speed_squared = velocity.into_iter().fold(T::zero(), |sum, v| sum + v * v);
+        Self::event(T::one(), velocity) / (T::one() - speed_squared).sqrt()
+    }
+
+    /// Time and relative position of a spacetime vector, i.e. the scalar and vector parts of
+    /// `self γ0`.
+    pub fn split(self) -> (T, [T; 3]) {
+        let product = self * Self::base_element(GAMMA[0]);
+        // the `σi` square to `1`
+        let relative = [0, 1, 2].map(|i| (product * Self::sigma(i))[0]);
+        (product[0], relative)
+    }
+
+    /// The relative velocity of a timelike vector, e.g. a four-velocity or momentum.
+    pub fn velocity(self) -> [T; 3] {
+        let (t, x) = self.split();
+        x.map(|x| x / t)
+    }
+
+    /// The rotor `exp(φ/2 n σ)` boosting by the `rapidity` `φ` along the `direction` `n`, i.e.
+    /// to the velocity `tanh φ`.
+    pub fn boost(direction: [T; 3], rapidity: T) -> Self {
+        let generator = Self::relative(direction);
+        let length = (generator * generator)[0].sqrt();
+        (generator * (rapidity / (length + length))).exp()
+    }
+
+    /// The rotor `exp(-θ/2 I n)` rotating by `angle` `θ` around the relative `axis` `n`.
+    pub fn rotation(axis: [T; 3], angle: T) -> Self {
+        let generator = Self::base_element(PSEUDOSCALAR) * Self::relative(axis);
+        let length = (generator * generator.reverse())[0].sqrt();
+        (generator * (-angle / (length + length))).exp()
+    }
+
+    /// `self * x * reverse(self)`, applies a rotor (Lorentz transformation) to `x`.
+    pub fn sandwich(self, x: Self) -> Self {
+        self * x * self.reverse()
+    }
+
+    /// The Faraday bivector `E + I B` of the electric field `e` and the magnetic field `b`.
+    pub fn faraday(e: [T; 3], b: [T; 3]) -> Self {
+        Self::relative(e) + Self::base_element(PSEUDOSCALAR) * Self::relative(b)
+    }
+
+    /// The electric and magnetic fields of a Faraday bivector, as seen by the observer `γ0`.
+    pub fn fields(self) -> ([T; 3], [T; 3]) {
+        let pseudoscalar = Self::base_element(PSEUDOSCALAR);
+        let e = [0, 1, 2].map(|i| (self * Self::sigma(i))[0]);
+        // the `I σi` square to `-1`
+        let b = [0, 1, 2].map(|i| -(self * pseudoscalar * Self::sigma(i))[0]);
+        (e, b)
+    }
+
+    /// The Lorentz force `q F·v` of the field `self` on a particle with `charge` `q` and
+    /// four-velocity `v`, i.e. the change of momentum per proper time.
+    pub fn lorentz_force(self, charge: T, four_velocity: Self) -> Self {
+        inner_product(self, four_velocity) * charge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sta = StaMultiVector<f64>;
+
+    fn close(a: [f64; 3], b: [f64; 3]) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-12)
+    }
+
+    fn gamma(v: f64) -> f64 {
+        1.0 / (1.0 - v * v).sqrt()
+    }
+
+    #[test]
+    fn split() {
+        let x = Sta::event(2.0, [1.0, 2.0, 3.0]);
+        assert_eq!(x.split(), (2.0, [1.0, 2.0, 3.0]));
+        // the spacetime interval
+        assert_eq!((x * x)[0], 4.0 - 14.0);
+        for i in 0..3 {
+            assert_eq!((Sta::sigma(i) * Sta::sigma(i))[0], 1.0);
+        }
+
+        let u = Sta::four_velocity([0.6, 0.0, 0.0]);
+        assert!(((u * u)[0] - 1.0).abs() < 1e-12);
+        assert!((u.split().0 - 1.25).abs() < 1e-12);
+        assert!(close(u.velocity(), [0.6, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn boosts() {
+        let rest = Sta::event(1.0, [0.0; 3]);
+        let direction = [0.0, 3.0, 4.0];
+        let boost = Sta::boost(direction, 0.8f64.atanh());
+        assert!(((boost * boost.reverse())[0] - 1.0).abs() < 1e-12);
+        // time dilation: the moving clock's four-velocity has `t = γ`
+        let u = boost.sandwich(rest);
+        assert!((u.split().0 - gamma(0.8)).abs() < 1e-12);
+        assert!(close(u.velocity(), [0.0, 0.48, 0.64]));
+        // the interval is invariant
+        let x = Sta::event(3.0, [1.0, -2.0, 0.5]);
+        assert!(((boost.sandwich(x) * boost.sandwich(x))[0] - (x * x)[0]).abs() < 1e-12);
+
+        // collinear boosts compose to the relativistic sum of velocities
+        let (v, w) = (0.5, 0.6);
+        let a = Sta::boost([1.0, 0.0, 0.0], f64::atanh(v));
+        let b = Sta::boost([1.0, 0.0, 0.0], f64::atanh(w));
+        let composed = (b * a).sandwich(rest).velocity();
+        assert!(close(composed, [(v + w) / (1.0 + v * w), 0.0, 0.0]));
+        let sum = Sta::boost([1.0, 0.0, 0.0], f64::atanh(v) + f64::atanh(w));
+        assert!((b * a - sum).into_iter().all(|x| x.abs() < 1e-12));
+    }
+
+    #[test]
+    fn rotations() {
+        let rotation = Sta::rotation([0.0, 0.0, 2.0], std::f64::consts::FRAC_PI_2);
+        let x = rotation.sandwich(Sta::event(5.0, [1.0, 0.0, 3.0]));
+        assert!((x.split().0 - 5.0).abs() < 1e-12);
+        assert!(close(x.split().1, [0.0, 1.0, 3.0]));
+    }
+
+    #[test]
+    fn wigner_rotation() {
+        // perpendicular boosts compose to a boost and a rotation around their normal
+        let (v, w) = (0.6, 0.8);
+        let a = Sta::boost([1.0, 0.0, 0.0], f64::atanh(v));
+        let b = Sta::boost([0.0, 1.0, 0.0], f64::atanh(w));
+        let composed = b * a;
+        let rest = Sta::event(1.0, [0.0; 3]);
+        let u = composed.sandwich(rest);
+        assert!(close(u.velocity(), [v / gamma(w), w, 0.0]));
+
+        // the pure boost from rest to `u` is `(1 + u γ0) / sqrt(2 (1 + γ))`
+        let (t, _) = u.split();
+        let pure = (Sta::base_element(0) + u * rest) / (2.0 * (1.0 + t)).sqrt();
+        assert!(close(pure.sandwich(rest).velocity(), u.velocity()));
+        let rotation = pure.reverse() * composed;
+        let (gamma_v, gamma_w) = (gamma(v), gamma(w));
+        let angle = ((gamma_v + gamma_w) / (1.0 + gamma_v * gamma_w)).acos();
+        let expected = Sta::rotation([0.0, 0.0, 1.0], angle);
+        assert!((rotation - expected).into_iter().all(|x| x.abs() < 1e-12));
+    }
+
+    #[test]
+    fn fields() {
+        let (e, b) = ([1.0, 2.0, 3.0], [-4.0, 5.0, 0.5]);
+        let f = Sta::faraday(e, b);
+        assert_eq!(f.fields(), (e, b));
+        // `F² = E² - B² + 2 I E·B`
+        let square = f * f;
+        assert_eq!(square[0], 14.0 - 41.25);
+        assert_eq!(square[PSEUDOSCALAR], 2.0 * 7.5);
+
+        // an observer moving with `v` along `x` sees `E' = γ E` and `B' = -γ v × E`
+        let v = 0.6;
+        let f = Sta::faraday([0.0, 1.0, 0.0], [0.0; 3]);
+        let boost = Sta::boost([1.0, 0.0, 0.0], f64::atanh(v));
+        let (e, b) = boost.reverse().sandwich(f).fields();
+        assert!(close(e, [0.0, gamma(v), 0.0]));
+        assert!(close(b, [0.0, 0.0, -gamma(v) * v]));
+        assert!(((boost.sandwich(f) * boost.sandwich(f))[0] - (f * f)[0]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn lorentz_force() {
+        // at rest, only the electric field acts
+        let f = Sta::faraday([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+        let force = f.lorentz_force(2.0, Sta::event(1.0, [0.0; 3]));
+        assert!((force.split().0).abs() < 1e-12);
+        assert!(close(force.split().1, [2.0, 4.0, 6.0]));
+
+        // `q γ v × B` for a magnetic field, perpendicular to the velocity
+        let v = [0.5, 0.0, 0.0];
+        let u = Sta::four_velocity(v);
+        let force = Sta::faraday([0.0; 3], [0.0, 0.0, 1.0]).lorentz_force(3.0, u);
+        assert!((force.split().0).abs() < 1e-12);
+        assert!(close(force.split().1, [0.0, -1.5 * gamma(0.5), 0.0]));
+        assert!((force * u)[0].abs() < 1e-12);
+    }
+}
@@ -0,0 +1,484 @@
+//! This module contains [Symbol], a symbolic [Scalar] for deriving specialized formulas.
+//!
+//! The generated products are generic over the coefficients, so feeding in named coefficients
+//! yields the closed-form formulas, with like terms collected and zero terms removed. E.g. the
+//! sandwich of a vector with a rotor of the plane:
+//!
+//! ```
+//! # use algebraic::{symbol::variables, MultiVector2, RingMultiVector};
+//! let mut rotor: MultiVector2<_> = variables("r");
+//! rotor[1] = Default::default();
+//! rotor[2] = Default::default();
+//! let mut x: MultiVector2<_> = variables("x");
+//! x[0] = Default::default();
+//! x[3] = Default::default();
+//!
+//! let rotated = rotor * x * rotor.reverse();
+//! assert_eq!(rotated[0].to_string(), "0");
+//! assert_eq!(rotated[1].to_string(), "-2*r0*r3*x2 + r0^2*x1 - r3^2*x1");
+//! assert_eq!(rotated[3].to_string(), "0");
+//! ```
+//!
+//! Symbols are handles into a thread-local arena in which every expression is stored once, so
+//! they are [Copy] and compare in constant time, but only meaningful on the thread that created
+//! them. The arena is never cleared, it grows with every expression created on the thread,
+//! including the intermediate ones, until the thread exits. For long-running programs, derive
+//! formulas on a short-lived thread, e.g. with [std::thread::scope], and keep the rendered
+//! strings or [evaluated](Symbol::evaluate) values.
+use crate::{RingMultiVector, Scalar};
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A symbolic expression, i.e. a polynomial in named variables and unevaluated functions.
+///
+/// Sums and products are expanded and normalized, so e.g. `a * b - b * a` is `0`. Functions of
+/// constants are evaluated, other functions (and divisions by sums) stay unevaluated. Equality is
+/// structural, and only constants are ordered.
+///
+/// [MultiVector::inverse](crate::MultiVector::inverse) and
+/// [MultiVector::exp](crate::MultiVector::exp) work symbolically, but their results grow quickly,
+/// this is meant for products.
+///
+/// Every expression is kept in a thread-local arena that only grows, see the
+/// [module documentation](self).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(u32, PhantomData<*const ()>);
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Atom {
+    Variable(String),
+    Function(&'static str, Vec<Symbol>),
+    Reciprocal(Symbol),
+}
+
+// (atom, exponent) sorted by atom, no zero exponents
+type Monomial = Vec<(u32, i32)>;
+
+// (monomial, coefficient bits) sorted by monomial, no zero coefficients
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+struct Polynomial(Vec<(Monomial, u64)>);
+
+struct Arena {
+    atoms: Vec<Atom>,
+    atom_ids: HashMap<Atom, u32>,
+    polynomials: Vec<Polynomial>,
+    polynomial_ids: HashMap<Polynomial, u32>,
+}
+
+thread_local! {
+    static ARENA: RefCell<Arena> = RefCell::new(Arena {
+        atoms: Vec::new(),
+        atom_ids: HashMap::new(),
+        // zero comes first, for `Default`
+        polynomials: vec![Polynomial::default()],
+        polynomial_ids: HashMap::from([(Polynomial::default(), 0)]),
+    });
+}
+
+impl Symbol {
+    /// A variable with the given name.
+    pub fn new(name: &str) -> Self {
+        Self::atom(Atom::Variable(name.to_owned()))
+    }
+
+    pub fn constant(value: f64) -> Self {
+        Self::from_terms([(Monomial::new(), value)])
+    }
+
+    /// The value, if this is a constant.
+    pub fn value(self) -> Option<f64> {
+        match self.polynomial().0.as_slice() {
+            [] => Some(0.0),
+            [(monomial, coefficient)] if monomial.is_empty() => Some(f64::from_bits(*coefficient)),
+            _ => None,
+        }
+    }
+
+    /// Evaluates with the given values of the variables.
+    pub fn evaluate(self, variable: &dyn Fn(&str) -> f64) -> f64 {
+        let atom = |id: u32| match ARENA.with(|arena| arena.borrow().atoms[id as usize].clone()) {
+            Atom::Variable(name) => variable(&name),
+            Atom::Function(name, arguments) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| argument.evaluate(variable))
+                    .collect::<Vec<_>>();
+                evaluate_function(name, &arguments)
+            }
+            Atom::Reciprocal(symbol) => 1.0 / symbol.evaluate(variable),
+        };
+        self.polynomial()
+            .0
+            .iter()
+            .map(|(monomial, coefficient)| {
+                monomial
+                    .iter()
+                    .fold(f64::from_bits(*coefficient), |product, &(id, exponent)| {
+                        product * atom(id).powi(exponent)
+                    })
+            })
+            .sum()
+    }
+
+    fn polynomial(self) -> Polynomial {
+        ARENA.with(|arena| arena.borrow().polynomials[self.0 as usize].clone())
+    }
+
+    fn intern(polynomial: Polynomial) -> Self {
+        ARENA.with(|arena| {
+            let mut arena = arena.borrow_mut();
+            let next = arena.polynomials.len() as u32;
+            let id = *arena
+                .polynomial_ids
+                .entry(polynomial.clone())
+                .or_insert(next);
+            if id == next {
+                arena.polynomials.push(polynomial);
+            }
+            Self(id, PhantomData)
+        })
+    }
+
+    fn atom(atom: Atom) -> Self {
+        let id = ARENA.with(|arena| {
+            let mut arena = arena.borrow_mut();
+            let next = arena.atoms.len() as u32;
+            let id = *arena.atom_ids.entry(atom.clone()).or_insert(next);
+            if id == next {
+                arena.atoms.push(atom);
+            }
+            id
+        });
+        Self::from_terms([(vec![(id, 1)], 1.0)])
+    }
+
+    // collects like terms and removes zeros
+    fn from_terms(terms: impl IntoIterator<Item = (Monomial, f64)>) -> Self {
+        let mut collected = BTreeMap::<Monomial, f64>::new();
+        for (monomial, coefficient) in terms {
+            *collected.entry(monomial).or_default() += coefficient;
+        }
+        Self::intern(Polynomial(
+            collected
+                .into_iter()
+                .filter(|(_, coefficient)| *coefficient != 0.0)
+                .map(|(monomial, coefficient)| (monomial, coefficient.to_bits()))
+                .collect(),
+        ))
+    }
+
+    fn terms(self) -> impl Iterator<Item = (Monomial, f64)> {
+        self.polynomial()
+            .0
+            .into_iter()
+            .map(|(monomial, coefficient)| (monomial, f64::from_bits(coefficient)))
+    }
+
+    // evaluated for constants, unevaluated otherwise
+    fn function(name: &'static str, arguments: Vec<Symbol>) -> Self {
+        match arguments
+            .iter()
+            .map(|a| a.value())
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(values) => Self::constant(evaluate_function(name, &values)),
+            None => Self::atom(Atom::Function(name, arguments)),
+        }
+    }
+
+    fn render(self, precision: Option<usize>) -> String {
+        let number = |x: f64| match precision {
+            Some(precision) => format!("{x:.precision$}"),
+            None => x.to_string(),
+        };
+        let atom = |id: u32| match ARENA.with(|arena| arena.borrow().atoms[id as usize].clone()) {
+            Atom::Variable(name) => (name, 1),
+            Atom::Function(name, arguments) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| argument.render(precision))
+                    .collect::<Vec<_>>();
+                (format!("{name}({})", arguments.join(", ")), 1)
+            }
+            Atom::Reciprocal(symbol) => (format!("({})", symbol.render(precision)), -1),
+        };
+
+        let mut rendered = String::new();
+        for (i, (monomial, coefficient)) in self.terms().enumerate() {
+            rendered += match (i, coefficient < 0.0) {
+                (0, false) => "",
+                (0, true) => "-",
+                (_, false) => " + ",
+                (_, true) => " - ",
+            };
+
+            let (mut numerator, mut denominator) = (Vec::new(), Vec::new());
+            for &(id, exponent) in &monomial {
+                let (base, sign) = atom(id);
+                let exponent = exponent * sign;
+                let factor = match exponent.abs() {
+                    1 => base,
+                    power => format!("{base}^{power}"),
+                };
+                match exponent > 0 {
+                    true => numerator.push(factor),
+                    false => denominator.push(factor),
+                }
+            }
+
+            let mut term = Vec::new();
+            if coefficient.abs() != 1.0 || numerator.is_empty() {
+                term.push(number(coefficient.abs()));
+            }
+            term.extend(numerator);
+            rendered += &term.join("*");
+            for factor in denominator {
+                rendered += &format!("/{factor}");
+            }
+        }
+        if rendered.is_empty() {
+            rendered = number(0.0);
+        }
+        rendered
+    }
+}
+
+fn evaluate_function(name: &str, arguments: &[f64]) -> f64 {
+    match (name, arguments) {
+        ("abs", [x]) => x.abs(),
+        ("sqrt", [x]) => x.sqrt(),
+        ("exp", [x]) => x.exp(),
+        ("ln", [x]) => x.ln(),
+        ("sin", [x]) => x.sin(),
+        ("cos", [x]) => x.cos(),
+        ("acos", [x]) => x.acos(),
+        ("atan2", [y, x]) => y.atan2(*x),
+        ("sinh", [x]) => x.sinh(),
+        ("cosh", [x]) => x.cosh(),
+        _ => unreachable!("unknown function {name}"),
+    }
+}
+
+/// A multi vector with the variables `{prefix}0`, `{prefix}1`, ... as coefficients.
+pub fn variables<M: RingMultiVector<Symbol>>(prefix: &str) -> M {
+    let mut variables = M::default();
+    for i in 0..M::BASE_SIZE {
+        variables[i] = Symbol::new(&format!("{prefix}{i}"));
+    }
+    variables
+}
+
+impl Default for Symbol {
+    fn default() -> Self {
+        Self(0, PhantomData)
+    }
+}
+
+impl Add for Symbol {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_terms(self.terms().chain(rhs.terms()))
+    }
+}
+
+impl Sub for Symbol {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Symbol {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let rhs = rhs.terms().collect::<Vec<_>>();
+        Self::from_terms(self.terms().flat_map(|(a, x)| {
+            rhs.iter().map(move |(b, y)| {
+                let mut exponents = BTreeMap::<u32, i32>::new();
+                for &(id, exponent) in a.iter().chain(b) {
+                    *exponents.entry(id).or_default() += exponent;
+                }
+                exponents.retain(|_, exponent| *exponent != 0);
+                (exponents.into_iter().collect(), x * y)
+            })
+        }))
+    }
+}
+
+/// Divisions by single terms cancel, divisions by sums stay unevaluated.
+impl Div for Symbol {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        match rhs.terms().collect::<Vec<_>>().as_slice() {
+            [(monomial, coefficient)] => {
+                let inverse = monomial.iter().map(|&(id, exponent)| (id, -exponent));
+                self * Self::from_terms([(inverse.collect(), 1.0 / coefficient)])
+            }
+            _ => self * Self::atom(Atom::Reciprocal(rhs)),
+        }
+    }
+}
+
+impl Neg for Symbol {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_terms(
+            self.terms()
+                .map(|(monomial, coefficient)| (monomial, -coefficient)),
+        )
+    }
+}
+
+/// Constants are ordered by value, other symbols are only equal to themselves.
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.value(), other.value()) {
+            (Some(a), Some(b)) => a.partial_cmp(&b),
+            _ => (self == other).then_some(Ordering::Equal),
+        }
+    }
+}
+
+/// Real, i.e. [abs](Scalar::abs) is symbolic as well.
+impl Scalar for Symbol {
+    type Real = Self;
+
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn one() -> Self {
+        Self::constant(1.0)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Self::constant(value)
+    }
+
+    fn abs(self) -> Self {
+        Self::function("abs", vec![self])
+    }
+
+    fn sqrt(self) -> Self {
+        Self::function("sqrt", vec![self])
+    }
+
+    fn exp(self) -> Self {
+        Self::function("exp", vec![self])
+    }
+
+    fn ln(self) -> Self {
+        Self::function("ln", vec![self])
+    }
+
+    fn sin(self) -> Self {
+        Self::function("sin", vec![self])
+    }
+
+    fn cos(self) -> Self {
+        Self::function("cos", vec![self])
+    }
+
+    fn acos(self) -> Self {
+        Self::function("acos", vec![self])
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        Self::function("atan2", vec![self, other])
+    }
+
+    fn sinh(self) -> Self {
+        Self::function("sinh", vec![self])
+    }
+
+    fn cosh(self) -> Self {
+        Self::function("cosh", vec![self])
+    }
+}
+
+/// E.g. `2*a0*b1^2 - sqrt(x)/(a0 + b1)`, precision applies to the numbers.
+impl Display for Symbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(f.precision()))
+    }
+}
+
+impl Debug for Symbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiVector, MultiVector2, MultiVector3};
+
+    #[test]
+    fn simplification() {
+        let (a, b) = (Symbol::new("a"), Symbol::new("b"));
+        let two = Symbol::constant(2.0);
+        assert_eq!(a * b - b * a, Symbol::zero());
+        assert_eq!((a + b) * (a - b), a * a - b * b);
+        assert_eq!(a * b / a, b);
+        assert_eq!((a * two + b) / two - a, b * Symbol::constant(0.5));
+        assert_eq!((two * two).sqrt().value(), Some(2.0));
+        assert_eq!(Symbol::constant(0.0), Symbol::default());
+        assert!(two > Symbol::one());
+        assert_eq!(a.partial_cmp(&b), None);
+        assert_eq!(a.partial_cmp(&a), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn pretty_printing() {
+        let (a, b) = (Symbol::new("a"), Symbol::new("b"));
+        let two = Symbol::constant(2.0);
+        assert_eq!((a * a * b * two - b).to_string(), "2*a^2*b - b");
+        assert_eq!((Symbol::one() / (a * a)).to_string(), "1/a^2");
+        assert_eq!((a.sqrt() / (a + b)).to_string(), "sqrt(a)/(a + b)");
+        assert_eq!(b.atan2(a).to_string(), "atan2(b, a)");
+        assert_eq!(format!("{:.2}", a / Symbol::constant(3.0)), "0.33*a");
+        assert_eq!(Symbol::default().to_string(), "0");
+
+        let a: MultiVector2<_> = variables("a");
+        let e12 = MultiVector2::base_element(3);
+        assert_eq!((a * e12).to_string(), "-a3 + (a2)e1 - (a1)e2 + (a0)e12");
+    }
+
+    #[test]
+    fn rotor_sandwich() {
+        let mut rotor: MultiVector3<_> = variables("r");
+        let mut x: MultiVector3<_> = variables("x");
+        for i in 0..8 {
+            match i {
+                1 | 2 | 4 | 7 => rotor[i] = Symbol::zero(),
+                _ => x[i] = Symbol::zero(),
+            }
+        }
+        x[7] = Symbol::zero();
+        let rotated = rotor * x * rotor.reverse();
+        // rotors map vectors to vectors
+        for i in [0, 3, 5, 6, 7] {
+            assert_eq!(rotated[i], Symbol::zero(), "{i}: {}", rotated[i]);
+        }
+
+        // agrees with the numeric product
+        let value = |name: &str| name[1..].parse::<f64>().unwrap() * 0.1 - 0.2;
+        let numeric = |a: MultiVector3<Symbol>| MultiVector3(a.0.map(|x| x.evaluate(&value)));
+        let expected = numeric(rotor) * numeric(x) * numeric(rotor).reverse();
+        for (symbol, expected) in rotated.into_iter().zip(expected) {
+            assert!((symbol.evaluate(&value) - expected).abs() < 1e-12);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::multi_vector::MultiVector;
+use crate::multi_vector::{MultiVector, RingMultiVector};
 
 use super::*;
 
@@ -11,7 +11,7 @@ fn setup_rng() -> ChaCha8Rng {
 }
 
 fn random_float(rng: &mut ChaCha8Rng) -> f64 {
-    rng.next_u64() as f64 / std::u64::MAX as f64 * 20.0 - 10.0
+    rng.next_u64() as f64 / u64::MAX as f64 * 20.0 - 10.0
 }
 
 fn random_vector<T: MultiVector<f64>>(rng: &mut ChaCha8Rng) -> T {
@@ -35,10 +35,34 @@ fn random_samples<T: MultiVector<f64>>(rng: &mut ChaCha8Rng) -> Vec<T> {
     (0..SAMPLES).map(|_| random_multi_vector(rng)).collect()
 }
 
-fn approx<T: MultiVector<f64>>(a: T, b: T) -> bool {
-    a.into_iter()
-        .zip(b.into_iter())
-        .all(|(a, b)| (a - b).abs() < 0.0001)
+// absolute and relative tolerance of the floating point comparisons
+const EPSILON: f64 = 1e-9;
+
+// the approx traits of the multi vectors if available, per coefficient otherwise
+#[cfg(feature = "approx")]
+trait Approx: approx::RelativeEq<Epsilon = f64> {}
+#[cfg(feature = "approx")]
+impl<T: approx::RelativeEq<Epsilon = f64>> Approx for T {}
+#[cfg(not(feature = "approx"))]
+trait Approx {}
+#[cfg(not(feature = "approx"))]
+impl<T> Approx for T {}
+
+fn close<T: MultiVector<f64> + Approx>(a: T, b: T) -> bool {
+    #[cfg(feature = "approx")]
+    return a.relative_eq(&b, EPSILON, EPSILON);
+    #[cfg(not(feature = "approx"))]
+    return a
+        .into_iter()
+        .zip(b)
+        .all(|(a, b)| approx::relative_eq!(a, b, epsilon = EPSILON, max_relative = EPSILON));
+}
+
+macro_rules! assert_close {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = ($a, $b);
+        assert!(close(a, b), "{a:?} != {b:?}");
+    }};
 }
 
 fn permutation_even(permutation: &[usize]) -> bool {
@@ -57,69 +81,330 @@ fn permutation_even(permutation: &[usize]) -> bool {
 }
 
 fn factorial(n: usize) -> usize {
-    (1..=n).fold(1, |f, i| f * i)
+    (1..=n).product()
 }
 
-#[generic_tests::define]
-mod tests {
-    use super::*;
+fn random_integers<T: RingMultiVector<i64>>(rng: &mut ChaCha8Rng) -> T {
+    let mut mv = T::default();
+    for i in 0..T::BASE_SIZE {
+        mv[i] = (rng.next_u64() % 201) as i64 - 100;
+    }
+    mv
+}
 
-    // ab == (ab + ba) / 2 + (ab - ba) / 2
-    #[test]
-    fn antisymmetry<T: MultiVector<f64>>() {
-        let samples: Vec<T> = random_samples(&mut setup_rng());
-        for &a in &samples {
-            for &b in &samples {
-                let ab = a * b;
-                let ba = b * a;
+// the products over the integers and modulo P agree, and are exactly associative
+fn check_mod_p<A, B, const P: u64>()
+where
+    A: RingMultiVector<i64>,
+    B: RingMultiVector<ModP<P>> + PartialEq,
+{
+    let mut rng = setup_rng();
+    let reduce = |a: A| {
+        let mut b = B::default();
+        for i in 0..A::BASE_SIZE {
+            b[i] = ModP::new(a[i]);
+        }
+        b
+    };
+    for _ in 0..SAMPLES {
+        let a: A = random_integers(&mut rng);
+        let b: A = random_integers(&mut rng);
+        let c: A = random_integers(&mut rng);
+        assert_eq!(reduce(a * b), reduce(a) * reduce(b));
+        assert_eq!(reduce(a.reverse()), reduce(a).reverse());
+        assert_eq!(
+            (reduce(a) * reduce(b)) * reduce(c),
+            reduce(a) * (reduce(b) * reduce(c))
+        );
+    }
+}
 
-                assert!(approx(ab, (ab + ba) * 0.5 + (ab - ba) * 0.5));
-            }
+#[test]
+fn mod_p_products() {
+    check_mod_p::<MultiVector0<i64>, MultiVector0<ModP<7>>, 7>();
+    check_mod_p::<MultiVector1<i64>, MultiVector1<ModP<2>>, 2>();
+    check_mod_p::<MultiVector2<i64>, MultiVector2<ModP<3>>, 3>();
+    check_mod_p::<MultiVector3<i64>, MultiVector3<ModP<7>>, 7>();
+    check_mod_p::<MultiVector4<i64>, MultiVector4<ModP<65537>>, 65537>();
+    check_mod_p::<MultiVector5<i64>, MultiVector5<ModP<5>>, 5>();
+    check_mod_p::<MultiVector6<i64>, MultiVector6<ModP<11>>, 11>();
+}
+
+type Rational = num_rational::Ratio<i128>;
+
+// numerators in -50..=50, denominators 1, 3, 5, or 7, keeping exact products below 2^53
+fn random_rationals<T: RingMultiVector<Rational>>(rng: &mut ChaCha8Rng) -> T {
+    let mut mv = T::default();
+    for i in 0..T::BASE_SIZE {
+        let numerator = (rng.next_u64() % 101) as i128 - 50;
+        let denominator = [1, 3, 5, 7][(rng.next_u64() % 4) as usize];
+        mv[i] = Rational::new(numerator, denominator);
+    }
+    mv
+}
+
+fn encloses(interval: Interval, exact: Rational) -> bool {
+    use num_rational::BigRational;
+
+    let big = |x: f64| BigRational::from_float(x).unwrap();
+    let integer = |x: i128| {
+        assert!(x.unsigned_abs() < 1 << f64::MANTISSA_DIGITS);
+        big(x as f64)
+    };
+    let exact = integer(*exact.numer()) / integer(*exact.denom());
+    big(interval.lo()) <= exact && exact <= big(interval.hi())
+}
+
+// the products of intervals around rationals contain the exact products of the rationals
+fn check_interval<A, B>()
+where
+    A: RingMultiVector<Rational>,
+    B: RingMultiVector<Interval>,
+{
+    let mut rng = setup_rng();
+    let enclose = |a: A| {
+        let mut b = B::default();
+        for i in 0..A::BASE_SIZE {
+            let [numerator, denominator] = [a[i].numer(), a[i].denom()].map(|x| *x as f64);
+            b[i] = Interval::point(numerator) / Interval::point(denominator);
+        }
+        b
+    };
+    let check = |exact: A, interval: B| {
+        for i in 0..A::BASE_SIZE {
+            assert!(
+                encloses(interval[i], exact[i]),
+                "{:?} {:?}",
+                interval[i],
+                exact[i]
+            );
         }
+    };
+    for _ in 0..SAMPLES {
+        let a: A = random_rationals(&mut rng);
+        let b: A = random_rationals(&mut rng);
+        let c: A = random_rationals(&mut rng);
+        check(a, enclose(a));
+        check(a * b, enclose(a) * enclose(b));
+        check(a * b - c, enclose(a) * enclose(b) - enclose(c));
+        check(
+            (a * b).reverse() * c,
+            (enclose(a) * enclose(b)).reverse() * enclose(c),
+        );
     }
+}
 
-    // (ab)c == a(bc)
-    #[test]
-    fn associativity<T: MultiVector<f64>>() {
-        let samples: Vec<T> = random_samples(&mut setup_rng());
-        for &a in &samples {
-            for &b in &samples {
-                for &c in &samples {
-                    assert!(approx((a * b) * c, a * (b * c)));
+#[test]
+fn interval_products() {
+    check_interval::<MultiVector0<Rational>, MultiVector0<Interval>>();
+    check_interval::<MultiVector1<Rational>, MultiVector1<Interval>>();
+    check_interval::<MultiVector2<Rational>, MultiVector2<Interval>>();
+    check_interval::<MultiVector3<Rational>, MultiVector3<Interval>>();
+    check_interval::<MultiVector4<Rational>, MultiVector4<Interval>>();
+    check_interval::<MultiVector5<Rational>, MultiVector5<Interval>>();
+    check_interval::<MultiVector6<Rational>, MultiVector6<Interval>>();
+}
+
+// the generated product sums exactly, for every signature up to dimension 4, and for the uniform
+// signatures and a random sample of mixed ones in dimensions 5 and 6, which have too many
+// signatures (243 and 729) to check all in reasonable time
+const SAMPLED_SIGNATURES: usize = 8;
+
+#[test]
+fn signature_laws() {
+    use algebraic_core::{generate_elements, generate_product_sums_with_signature, Signature};
+
+    let product = |sums: &[Vec<(bool, usize, usize)>], a: &[Rational], b: &[Rational]| {
+        sums.iter()
+            .map(|sum| {
+                sum.iter().fold(Rational::default(), |c, &(negate, i, j)| {
+                    if negate {
+                        c - a[i] * b[j]
+                    } else {
+                        c + a[i] * b[j]
+                    }
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    let add = |a: &[Rational], b: &[Rational]| a.iter().zip(b).map(|(a, b)| a + b).collect_vec();
+
+    let mut rng = setup_rng();
+    for dimension in 0..=6 {
+        let elements = generate_elements(dimension);
+        let unit = |i: usize| {
+            let mut u = vec![Rational::default(); elements.len()];
+            u[i] = Rational::from(1);
+            u
+        };
+        let random = |rng: &mut ChaCha8Rng| {
+            let mut a = vec![Rational::default(); elements.len()];
+            for x in &mut a {
+                *x = Rational::new((rng.next_u64() % 21) as i128 - 10, 3);
+            }
+            a
+        };
+
+        let signatures = if dimension <= 4 {
+            (0..dimension)
+                .map(|_| [-1, 0, 1])
+                .multi_cartesian_product()
+                // the empty product yields nothing
+                .chain((dimension == 0).then(Vec::new))
+                .collect_vec()
+        } else {
+            let mut signatures = [-1, 0, 1].map(|square| vec![square; dimension]).to_vec();
+            signatures.extend((0..SAMPLED_SIGNATURES).map(|_| {
+                (0..dimension)
+                    .map(|_| (rng.next_u64() % 3) as i8 - 1)
+                    .collect_vec()
+            }));
+            signatures
+        };
+        for squares in signatures {
+            let sums = generate_product_sums_with_signature(
+                &elements,
+                &Signature::from_squares(squares.clone()),
+            );
+            let mul = |a: &[Rational], b: &[Rational]| product(&sums, a, b);
+
+            // basis vectors square to the signature
+            for (d, &square) in squares.iter().enumerate() {
+                let expected = unit(0).iter().map(|x| x * square as i128).collect_vec();
+                assert_eq!(mul(&unit(1 << d), &unit(1 << d)), expected, "{squares:?}");
+            }
+
+            // d! pseudoscalar == sum (-1 if perm is odd) (unit vector permutations)
+            let mut sum = vec![Rational::default(); elements.len()];
+            for permutation in (0..dimension).permutations(dimension) {
+                let mut product = unit(0);
+                for d in &permutation {
+                    product = mul(&product, &unit(1 << d));
+                }
+                if !permutation_even(&permutation) {
+                    product.iter_mut().for_each(|x| *x = -*x);
                 }
+                sum = add(&sum, &product);
+            }
+            let d_factorial = Rational::from(factorial(dimension) as i128);
+            let pseudoscalar = unit(elements.len() - 1);
+            assert_eq!(
+                sum,
+                pseudoscalar.iter().map(|x| x * d_factorial).collect_vec()
+            );
+
+            for _ in 0..4 {
+                let (a, b, c) = (random(&mut rng), random(&mut rng), random(&mut rng));
+                assert_eq!(mul(&mul(&a, &b), &c), mul(&a, &mul(&b, &c)), "{squares:?}");
+                assert_eq!(mul(&a, &add(&b, &c)), add(&mul(&a, &b), &mul(&a, &c)));
+                assert_eq!(mul(&add(&a, &b), &c), add(&mul(&a, &c), &mul(&b, &c)));
             }
         }
     }
+}
+
+#[test]
+fn equality() {
+    use std::collections::HashSet;
 
-    // a(b + c) == ab + ac
+    let a = MultiVector2([1, 2, 3, 4]);
+    assert_eq!(a, MultiVector2([1, 2, 3, 4]));
+    assert_ne!(a, MultiVector2([1, 2, 3, 5]));
+    assert_eq!(MultiVector1([0.0, 1.0]), MultiVector1([-0.0, 1.0]));
+    assert_ne!(MultiVector1([f64::NAN, 1.0]), MultiVector1([f64::NAN, 1.0]));
+
+    let set = HashSet::from([a, a * 2, a * 2, a + a]);
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn layout() {
+    use std::mem::{align_of, size_of};
+
+    assert_eq!(size_of::<MultiVector3<f32>>(), size_of::<[f32; 8]>());
+    assert_eq!(align_of::<MultiVector3<f32>>(), align_of::<[f32; 8]>());
+    assert_eq!(size_of::<[MultiVector6<f64>; 3]>(), 3 * 64 * 8);
+    assert_eq!(size_of::<PgaMultiVector<u8>>(), 16);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck_casts() {
+    let buffer = [
+        MultiVector3([1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]),
+        MultiVector3([-1.0; 8]),
+    ];
+    let bytes: &[u8] = bytemuck::cast_slice(&buffer);
+    assert_eq!(bytes.len(), 2 * 8 * 4);
+    assert_eq!(bytes[4..8], 2.0f32.to_ne_bytes());
+    assert_eq!(bytemuck::cast_slice::<u8, MultiVector3<f32>>(bytes), buffer);
+    let floats: &[f32] = bytemuck::cast_slice(&buffer);
+    assert_eq!(floats[8], -1.0);
+    use bytemuck::Zeroable;
+    assert_eq!(MultiVector4::<f64>::zeroed(), MultiVector4::default());
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn approx_traits() {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+    let a = MultiVector3([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let b = a + MultiVector3([1e-12; 8]);
+    assert_abs_diff_eq!(a, b, epsilon = 1e-10);
+    assert_relative_eq!(a, b, max_relative = 1e-10);
+    assert_ulps_eq!(a * 0.1 * 10.0, a);
+    assert_relative_ne!(a, a * 1.001);
+    assert_relative_eq!(a * a.inverse().unwrap(), MultiVector3::base_element(0));
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn zero_one() {
+    use num_traits::{One, Zero};
+
+    let a = MultiVector3([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(a * MultiVector3::one(), a);
+    assert_eq!(MultiVector3::<f64>::one() * a, a);
+    assert_eq!(a + MultiVector3::zero(), a);
+    assert!((a - a).is_zero() && !a.is_zero());
+    assert_eq!(MultiVector2::<f32>::one(), MultiVector2::base_element(0));
+}
+
+#[generic_tests::define]
+mod tests {
+    use super::*;
+
+    // ab == (ab + ba) / 2 + (ab - ba) / 2
     #[test]
-    fn distributivity<T: MultiVector<f64>>() {
+    fn antisymmetry<T: MultiVector<f64> + Approx>() {
         let samples: Vec<T> = random_samples(&mut setup_rng());
         for &a in &samples {
             for &b in &samples {
-                for &c in &samples {
-                    assert!(approx(a * (b + c), a * b + a * c,));
-                }
+                let ab = a * b;
+                let ba = b * a;
+
+                assert_close!(ab, (ab + ba) * 0.5 + (ab - ba) * 0.5);
             }
         }
     }
 
     // aa == scalar, where a is a vector
     #[test]
-    fn vector_square_scalar<T: MultiVector<f64>>() {
+    fn vector_square_scalar<T: MultiVector<f64> + Approx>() {
         let mut rng = setup_rng();
         for _ in 0..SAMPLES {
             let a: T = random_vector(&mut rng);
             let mut aa = a * a;
             aa[0] = 0.0;
 
-            assert!(approx(aa, T::default()));
+            assert_close!(aa, T::default());
         }
     }
 
     // a == ab b/bb, where b is a vector
     #[test]
-    fn vector_inverse<T: MultiVector<f64>>() {
+    fn vector_inverse<T: MultiVector<f64> + Approx>() {
         let mut rng = setup_rng();
         for _ in 0..SAMPLES {
             let a: T = random_multi_vector(&mut rng);
@@ -130,52 +415,60 @@ mod tests {
                 continue;
             }
 
-            assert!(approx(a, a * b * b / bb));
+            assert_close!(a, a * b * b / bb);
         }
     }
 
-    // pseudoscalar = 1/d! sum (-1 if perm is odd) (unit vector permutations)
+    // ~(ab) == ~b ~a
     #[test]
-    fn permutations_pseudoscalar<T: MultiVector<f64>>() {
-        let pseudoscalar = (0..T::ALGEBRA_DIMENSION)
-            .permutations(T::ALGEBRA_DIMENSION)
-            .map(|permutation| {
-                (
-                    permutation_even(&permutation),
-                    permutation.iter().fold(
-                        {
-                            // hacky unit scalar
-                            let mut s = T::default();
-                            s[0] = 1.0;
-                            s
-                        },
-                        |prod, d| {
-                            prod * {
-                                // hacky unit vector
-                                let mut u = T::default();
-                                u[1 << d] = 1.0;
-                                u
-                            }
-                        },
-                    ),
-                )
-            })
-            .fold(
-                T::default(),
-                |sum, (even, prod)| {
-                    if even {
-                        sum + prod
-                    } else {
-                        sum - prod
-                    }
-                },
-            )
-            / factorial(T::ALGEBRA_DIMENSION) as f64;
+    fn reverse_product<T: MultiVector<f64> + Approx>() {
+        let samples: Vec<T> = random_samples(&mut setup_rng());
+        for &a in &samples {
+            for &b in &samples {
+                assert_close!((a * b).reverse(), b.reverse() * a.reverse());
+            }
+        }
+    }
+
+    // a a^-1 == 1 == a^-1 a
+    #[test]
+    fn inverse<T: MultiVector<f64> + Approx>() {
+        let samples: Vec<T> = random_samples(&mut setup_rng());
+        for &a in &samples {
+            let inverse = a.inverse().unwrap();
+            assert_close!(a * inverse, T::base_element(0));
+            assert_close!(inverse * a, T::base_element(0));
+        }
+    }
 
-        let mut reference = T::default();
-        reference[T::BASE_SIZE - 1] = 1.0;
+    // exp(a) == cos|a| + a/|a| sin|a|, where a is a vector
+    #[test]
+    fn vector_exp<T: MultiVector<f64> + Approx>() {
+        let mut rng = setup_rng();
+        for _ in 0..SAMPLES {
+            let a: T = random_vector(&mut rng);
+            // vectors square to minus their squared norm
+            let norm = a.norm();
+            approx::assert_relative_eq!(norm * norm, -(a * a)[0], max_relative = EPSILON);
+            if norm == 0.0 {
+                continue;
+            }
+
+            assert_close!(
+                a.exp(),
+                T::base_element(0) * norm.cos() + a * (norm.sin() / norm)
+            );
+        }
+    }
 
-        assert!(approx(pseudoscalar, reference));
+    // exp(a) exp(-a) == 1
+    #[test]
+    fn exp_inverse<T: MultiVector<f64> + Approx>() {
+        let samples: Vec<T> = random_samples(&mut setup_rng());
+        for &a in &samples {
+            let a = a * 0.01;
+            assert_close!(a.exp() * (a * -1.0).exp(), T::base_element(0));
+        }
     }
 
     #[instantiate_tests(<MultiVector0<f64>>)]
@@ -193,3 +486,116 @@ mod tests {
     #[instantiate_tests(<MultiVector6<f64>>)]
     mod d6 {}
 }
+
+// exact, so that no tolerance can hide sign errors in the generated products
+#[generic_tests::define]
+mod exact_tests {
+    use super::*;
+
+    const SAMPLES: usize = 8;
+    fn random_samples<T: RingMultiVector<Rational>>(rng: &mut ChaCha8Rng) -> Vec<T> {
+        (0..SAMPLES).map(|_| random_rationals(rng)).collect()
+    }
+
+    // (ab)c == a(bc)
+    #[test]
+    fn associativity<T: RingMultiVector<Rational> + PartialEq>() {
+        let samples: Vec<T> = random_samples(&mut setup_rng());
+        for &a in &samples {
+            for &b in &samples {
+                for &c in &samples {
+                    assert_eq!((a * b) * c, a * (b * c));
+                }
+            }
+        }
+    }
+
+    // a(b + c) == ab + ac, (a + b)c == ac + bc
+    #[test]
+    fn distributivity<T: RingMultiVector<Rational> + PartialEq>() {
+        let samples: Vec<T> = random_samples(&mut setup_rng());
+        for &a in &samples {
+            for &b in &samples {
+                for &c in &samples {
+                    assert_eq!(a * (b + c), a * b + a * c);
+                    assert_eq!((a + b) * c, a * c + b * c);
+                }
+            }
+        }
+    }
+
+    // d! pseudoscalar == sum (-1 if perm is odd) (unit vector permutations)
+    #[test]
+    fn permutations_pseudoscalar<T: RingMultiVector<Rational> + PartialEq>() {
+        let unit = |i: usize| {
+            let mut u = T::default();
+            u[i] = Rational::from(1);
+            u
+        };
+        let sum = (0..T::ALGEBRA_DIMENSION)
+            .permutations(T::ALGEBRA_DIMENSION)
+            .fold(T::default(), |sum, permutation| {
+                let product = permutation
+                    .iter()
+                    .fold(unit(0), |product, d| product * unit(1 << d));
+                if permutation_even(&permutation) {
+                    sum + product
+                } else {
+                    sum - product
+                }
+            });
+
+        let d_factorial = Rational::from(factorial(T::ALGEBRA_DIMENSION) as i128);
+        assert_eq!(sum, unit(T::BASE_SIZE - 1) * d_factorial);
+    }
+
+    #[instantiate_tests(<MultiVector0<Rational>>)]
+    mod d0 {}
+    #[instantiate_tests(<MultiVector1<Rational>>)]
+    mod d1 {}
+    #[instantiate_tests(<MultiVector2<Rational>>)]
+    mod d2 {}
+    #[instantiate_tests(<MultiVector3<Rational>>)]
+    mod d3 {}
+    #[instantiate_tests(<MultiVector4<Rational>>)]
+    mod d4 {}
+    #[instantiate_tests(<MultiVector5<Rational>>)]
+    mod d5 {}
+    #[instantiate_tests(<MultiVector6<Rational>>)]
+    mod d6 {}
+    #[instantiate_tests(<PgaMultiVector<Rational>>)]
+    mod pga {}
+    #[instantiate_tests(<CgaMultiVector<Rational>>)]
+    mod cga {}
+    #[instantiate_tests(<StaMultiVector<Rational>>)]
+    mod sta {}
+}
+
+#[generic_tests::define]
+mod rotor_tests {
+    use super::*;
+    use crate::multi_vector::grade_part;
+
+    fn random_rotor<T: Rotor<f64>>(rng: &mut ChaCha8Rng) -> T {
+        grade_part(random_multi_vector::<T>(rng) * 0.2, 2)
+            .exp()
+            .normalized()
+    }
+
+    #[test]
+    fn rotation_matrix_round_trip<T: Rotor<f64> + Approx>() {
+        let mut rng = setup_rng();
+        for _ in 0..SAMPLES {
+            let rotor: T = random_rotor(&mut rng);
+            let extracted = T::from_rotation_matrix(rotor.to_rotation_matrix());
+            assert!(close(extracted, rotor) || close(extracted, rotor * -1.0));
+        }
+    }
+
+    #[instantiate_tests(<MultiVector2<f64>>)]
+    mod d2 {}
+    #[instantiate_tests(<MultiVector3<f64>>)]
+    mod d3 {}
+    #[instantiate_tests(<MultiVector4<f64>>)]
+    mod d4 {}
+}